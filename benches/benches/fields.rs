@@ -0,0 +1,75 @@
+use std::borrow::Cow;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use tabled::{display::ExpandedDisplay, Table, Tabled};
+
+#[derive(Tabled, Clone)]
+struct Entry {
+    name: String,
+    id: usize,
+    value: f64,
+    #[tabled(format = "{}%")]
+    percent: u8,
+    active: bool,
+}
+
+// An entry which is displayed only by `Tabled::fields`.
+#[derive(Clone)]
+struct ViaFields(Entry);
+
+impl Tabled for ViaFields {
+    const LENGTH: usize = Entry::LENGTH;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        self.0.fields()
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        Entry::headers()
+    }
+}
+
+fn entries(size: usize) -> Vec<Entry> {
+    let entry = Entry {
+        name: "This is a text 0".to_string(),
+        id: 1024,
+        value: 2.5,
+        percent: 50,
+        active: true,
+    };
+
+    vec![entry; size]
+}
+
+fn build(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build");
+    for size in [1024, 16384] {
+        let data = entries(size);
+
+        group.bench_with_input(BenchmarkId::new("table_new", size), &data, |b, data| {
+            b.iter(|| black_box(Table::new(data)));
+        });
+
+        // The same table built out of `Tabled::fields`, as `Table::new` did before `Tabled::visit_fields`.
+        let via_fields = data.iter().cloned().map(ViaFields).collect::<Vec<_>>();
+        group.bench_with_input(
+            BenchmarkId::new("table_fields", size),
+            &via_fields,
+            |b, data| {
+                b.iter(|| black_box(Table::new(data)));
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("expanded_display", size),
+            &data,
+            |b, data| {
+                b.iter(|| black_box(ExpandedDisplay::new(data)));
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, build);
+criterion_main!(benches);
@@ -113,7 +113,7 @@ fn create_small_table(style: RawStyle) -> Table {
 fn create_main_table(message: &str) -> Table {
     let count_lines = papergrid::util::count_lines(message);
     let message_width = papergrid::util::string_width_multiline_tab(message, 4);
-    let count_additional_separators = if count_lines > 2 { count_lines - 2 } else { 0 };
+    let count_additional_separators = count_lines.saturating_sub(2);
 
     let left_table = format!(
         "  ╔═══╗ \n  ╚═╦═╝ \n{}═╤══╩══╤\n ├──┬──┤\n └──┴──┘",
@@ -345,7 +345,7 @@ impl Printer for BasicPrinter<'_> {
         I: IntoIterator<Item = Table>,
     {
         let left_padding = Margin::new(10, 0, 0, 0);
-        for (_i, mut frame) in frames.into_iter().enumerate() {
+        for mut frame in frames.into_iter() {
             frame.with(left_padding.clone());
 
             queue!(self.stdout, Clear(ClearType::All), cursor::MoveTo(0, 7)).unwrap();
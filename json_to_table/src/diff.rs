@@ -0,0 +1,253 @@
+//! The module contains a [`JsonDiffTable`] which renders a difference of 2 [`Value`]s.
+
+use core::fmt::{self, Display};
+
+use serde_json::Value;
+use tabled::{builder::Builder, style::RawStyle, Table};
+
+use crate::{
+    select::{push_index, push_key},
+    table::{summarize_array, summarize_object},
+};
+
+#[cfg(feature = "color")]
+use tabled::color::Color;
+
+/// Converter of a difference between 2 [`Value`]s to a table.
+///
+/// Object keys and array indexes are aligned and compared one by one,
+/// keys which are present in only one of the objects are placed among the rest of the keys.
+/// Each row has a marker,
+/// `+` for an added entry, `-` for a removed one and `~` for a changed one.
+/// Unchanged objects and arrays are collapsed into a single row.
+/// The root value has a `$` path.
+///
+/// See [`json_diff_table`].
+///
+/// [`json_diff_table`]: crate::json_diff_table
+#[derive(Debug, Clone)]
+pub struct JsonDiffTable<'val> {
+    old: &'val Value,
+    new: &'val Value,
+    style: Option<RawStyle>,
+    hide_unchanged: bool,
+    #[cfg(feature = "color")]
+    colored: bool,
+}
+
+impl<'val> JsonDiffTable<'val> {
+    /// Creates a new [`JsonDiffTable`] object.
+    pub fn new(old: &'val Value, new: &'val Value) -> Self {
+        Self {
+            old,
+            new,
+            style: None,
+            hide_unchanged: false,
+            #[cfg(feature = "color")]
+            colored: false,
+        }
+    }
+
+    /// Set a style which will be used,
+    /// default is [`Style::ascii`].
+    ///
+    /// [`Style::ascii`]: tabled::Style::ascii
+    pub fn set_style(&mut self, style: impl Into<RawStyle>) -> &mut Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    /// Don't render entries which are equal in both values.
+    pub fn hide_unchanged(&mut self) -> &mut Self {
+        self.hide_unchanged = true;
+        self
+    }
+
+    /// Color entries by a kind of a change,
+    /// added values are green, removed ones are red and markers of changed ones are yellow.
+    #[cfg(feature = "color")]
+    pub fn colored(&mut self) -> &mut Self {
+        self.colored = true;
+        self
+    }
+
+    fn build(&self) -> Table {
+        let mut entries = Vec::new();
+        diff_r(
+            Some(self.old),
+            Some(self.new),
+            &mut String::new(),
+            &mut entries,
+        );
+
+        let mut builder = Builder::new();
+        builder.set_columns(["", "path", "old", "new"]);
+
+        for entry in entries {
+            if self.hide_unchanged && entry.change == Change::Unchanged {
+                continue;
+            }
+
+            let row = self.format_entry(entry);
+            builder.add_record(row);
+        }
+
+        let mut table = builder.build();
+        if let Some(style) = self.style.as_ref() {
+            table.with(style);
+        }
+
+        table
+    }
+
+    fn format_entry(&self, entry: Entry) -> [String; 4] {
+        let marker = match entry.change {
+            Change::Added => "+",
+            Change::Removed => "-",
+            Change::Changed => "~",
+            Change::Unchanged => "",
+        };
+
+        let path = if entry.path.is_empty() {
+            String::from("$")
+        } else {
+            entry.path
+        };
+
+        let row = [
+            marker.to_owned(),
+            path,
+            entry.old.unwrap_or_default(),
+            entry.new.unwrap_or_default(),
+        ];
+
+        #[cfg(feature = "color")]
+        if self.colored {
+            return colorize_row(row, entry.change);
+        }
+
+        row
+    }
+}
+
+impl Display for JsonDiffTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.build().fmt(f)
+    }
+}
+
+impl From<JsonDiffTable<'_>> for Table {
+    fn from(t: JsonDiffTable<'_>) -> Self {
+        t.build()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Added,
+    Removed,
+    Changed,
+    Unchanged,
+}
+
+#[derive(Debug)]
+struct Entry {
+    change: Change,
+    path: String,
+    old: Option<String>,
+    new: Option<String>,
+}
+
+impl Entry {
+    fn new(change: Change, path: &str, old: Option<&Value>, new: Option<&Value>) -> Self {
+        Self {
+            change,
+            path: path.to_owned(),
+            old: old.map(Value::to_string),
+            new: new.map(Value::to_string),
+        }
+    }
+}
+
+#[cfg(feature = "color")]
+fn colorize_row(mut row: [String; 4], change: Change) -> [String; 4] {
+    use crate::theme::colorize;
+
+    let marker_color = match change {
+        Change::Added => Color::FG_GREEN,
+        Change::Removed => Color::FG_RED,
+        Change::Changed => Color::FG_YELLOW,
+        Change::Unchanged => return row,
+    };
+
+    row[0] = colorize(&row[0], Some(&marker_color));
+    row[2] = colorize(&row[2], Some(&Color::FG_RED));
+    row[3] = colorize(&row[3], Some(&Color::FG_GREEN));
+
+    row
+}
+
+fn diff_r(old: Option<&Value>, new: Option<&Value>, path: &mut String, entries: &mut Vec<Entry>) {
+    match (old, new) {
+        (Some(old), Some(new)) if old == new => {
+            let text = match old {
+                Value::Object(map) => summarize_object(map.len()),
+                Value::Array(list) => summarize_array(list.len()),
+                value => value.to_string(),
+            };
+            entries.push(Entry {
+                change: Change::Unchanged,
+                path: path.clone(),
+                old: Some(text.clone()),
+                new: Some(text),
+            });
+        }
+        (Some(Value::Object(old)), Some(Value::Object(new))) => {
+            // keys are walked in the order of both objects,
+            // so an added key is placed before a common key which follows it in the new object.
+            let mut added = new.iter();
+            for (key, value) in old {
+                if new.contains_key(key) {
+                    for (key, value) in added.by_ref().take_while(|(k, _)| *k != key) {
+                        if !old.contains_key(key) {
+                            diff_key(key, None, Some(value), path, entries);
+                        }
+                    }
+                }
+
+                diff_key(key, Some(value), new.get(key), path, entries);
+            }
+
+            for (key, value) in added {
+                if !old.contains_key(key) {
+                    diff_key(key, None, Some(value), path, entries);
+                }
+            }
+        }
+        (Some(Value::Array(old)), Some(Value::Array(new))) => {
+            for i in 0..old.len().max(new.len()) {
+                let length = path.len();
+                push_index(path, i);
+                diff_r(old.get(i), new.get(i), path, entries);
+                path.truncate(length);
+            }
+        }
+        (Some(_), Some(_)) => entries.push(Entry::new(Change::Changed, path, old, new)),
+        (Some(_), None) => entries.push(Entry::new(Change::Removed, path, old, None)),
+        (None, Some(_)) => entries.push(Entry::new(Change::Added, path, None, new)),
+        (None, None) => {}
+    }
+}
+
+fn diff_key(
+    key: &str,
+    old: Option<&Value>,
+    new: Option<&Value>,
+    path: &mut String,
+    entries: &mut Vec<Entry>,
+) {
+    let length = path.len();
+    push_key(path, key);
+    diff_r(old, new, path, entries);
+    path.truncate(length);
+}
@@ -0,0 +1,135 @@
+//! The module contains a conversion of a [`Table`] back to a [`Value`].
+
+use std::collections::HashSet;
+
+use serde_json::{Map, Number, Value};
+use tabled::{papergrid::records::Records, Table};
+
+/// Options of a [`Table`] to [`Value`] conversion.
+///
+/// See [`table_to_json`].
+///
+/// [`table_to_json`]: crate::table_to_json
+#[derive(Debug, Clone, Default)]
+pub struct JsonOptions {
+    key_value: bool,
+    infer_types: bool,
+}
+
+impl JsonOptions {
+    /// Creates default options,
+    /// where a first row is used as keys and each other row is an object.
+    ///
+    /// A repeated key gets a numeric suffix, like `name_2`, so no column is lost.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Convert a table to a single object,
+    /// where a first column is used as keys and a second one as values.
+    ///
+    /// If a table has more than 2 columns a value is an array of the rest of the columns.
+    pub fn key_value(&mut self) -> &mut Self {
+        self.key_value = true;
+        self
+    }
+
+    /// Convert numbers and booleans to their types instead of keeping them as strings.
+    ///
+    /// A cell is converted to a number only when its text is a valid JSON number,
+    /// so texts like `+5` or `02134` are kept as strings.
+    ///
+    /// An empty cell becomes a `null`.
+    pub fn infer_types(&mut self) -> &mut Self {
+        self.infer_types = true;
+        self
+    }
+}
+
+pub(crate) fn table_to_json<R>(table: &Table<R>, opts: &JsonOptions) -> Value
+where
+    R: Records,
+{
+    let records = table.get_records();
+    let (count_rows, count_columns) = (records.count_rows(), records.count_columns());
+
+    if opts.key_value {
+        let mut map = Map::new();
+        for row in 0..count_rows {
+            if count_columns == 0 {
+                break;
+            }
+
+            let key = unique_key(records.get_text((row, 0)), |key| map.contains_key(key));
+            let value = match count_columns {
+                1 => Value::Null,
+                2 => convert_text(records.get_text((row, 1)), opts),
+                _ => Value::Array(
+                    (1..count_columns)
+                        .map(|col| convert_text(records.get_text((row, col)), opts))
+                        .collect(),
+                ),
+            };
+
+            map.insert(key, value);
+        }
+
+        return Value::Object(map);
+    }
+
+    let mut keys = Vec::with_capacity(count_columns);
+    let mut seen = HashSet::with_capacity(count_columns);
+    for col in 0..count_columns {
+        let key = unique_key(records.get_text((0, col)), |key| seen.contains(key));
+        seen.insert(key.clone());
+        keys.push(key);
+    }
+
+    let mut list = Vec::with_capacity(count_rows.saturating_sub(1));
+    for row in 1..count_rows {
+        let mut map = Map::new();
+        for (col, key) in keys.iter().enumerate() {
+            let value = convert_text(records.get_text((row, col)), opts);
+            map.insert(key.clone(), value);
+        }
+
+        list.push(Value::Object(map));
+    }
+
+    Value::Array(list)
+}
+
+fn convert_text(text: &str, opts: &JsonOptions) -> Value {
+    if !opts.infer_types {
+        return Value::String(text.to_owned());
+    }
+
+    match text {
+        "" => Value::Null,
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        text => parse_number(text).unwrap_or_else(|| Value::String(text.to_owned())),
+    }
+}
+
+// a text is converted only when it's exactly a JSON number,
+// so nothing like a sign or leading zeros is lost
+fn parse_number(text: &str) -> Option<Value> {
+    if text.trim() != text {
+        return None;
+    }
+
+    serde_json::from_str::<Number>(text).ok().map(Value::Number)
+}
+
+// a repeated key gets a suffix so it doesn't overwrite a previous one
+fn unique_key(key: &str, is_used: impl Fn(&str) -> bool) -> String {
+    if !is_used(key) {
+        return key.to_owned();
+    }
+
+    (2..)
+        .map(|i| format!("{}_{}", key, i))
+        .find(|key| !is_used(key))
+        .expect("an unused key must be found")
+}
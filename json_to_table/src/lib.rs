@@ -86,15 +86,143 @@
 
 use serde_json::Value;
 
-pub use table::{JsonTable, Orientation};
+pub use diff::JsonDiffTable;
+pub use from_table::JsonOptions;
+pub use ndjson::NdjsonTable;
+pub use table::{JsonTable, KeysOrder, Orientation};
+pub use value::{TableValue, ValueKind};
 
+#[cfg(feature = "color")]
+pub use theme::Theme;
+
+mod diff;
+mod from_table;
+mod ndjson;
+mod select;
 mod table;
+#[cfg(feature = "color")]
+mod theme;
+mod value;
 
 /// The function converts a given [`Value`] to a [`Table`].
 ///
 /// See the example in a module documentation.
 ///
+/// To convert any other [`TableValue`],
+/// like `toml::Value` or `serde_yaml::Value` when `toml` or `serde_yaml` features are on,
+/// use [`JsonTable::new`].
+///
 /// [`Table`]: tabled::Table
 pub fn json_to_table(value: &Value) -> JsonTable<'_> {
     JsonTable::new(value)
 }
+
+/// The function builds a table of differences between 2 [`Value`]s.
+///
+/// Each row contains a path to an entry, its old and new values.
+///
+/// # Example
+///
+/// ```
+/// use json_to_table::json_diff_table;
+/// use serde_json::json;
+///
+/// let old = json!({"name": "John Doe", "age": 43, "phones": ["+44 1234567"]});
+/// let new = json!({"name": "John Doe", "age": 44, "phones": ["+44 1234567", "+44 2345678"]});
+///
+/// let table = json_diff_table(&old, &new).to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+---+-----------+---------------+---------------+\n",
+///         "|   | path      | old           | new           |\n",
+///         "+---+-----------+---------------+---------------+\n",
+///         "| ~ | age       | 43            | 44            |\n",
+///         "+---+-----------+---------------+---------------+\n",
+///         "|   | name      | \"John Doe\"    | \"John Doe\"    |\n",
+///         "+---+-----------+---------------+---------------+\n",
+///         "|   | phones[0] | \"+44 1234567\" | \"+44 1234567\" |\n",
+///         "+---+-----------+---------------+---------------+\n",
+///         "| + | phones[1] |               | \"+44 2345678\" |\n",
+///         "+---+-----------+---------------+---------------+",
+///     ),
+/// );
+/// ```
+pub fn json_diff_table<'a>(old: &'a Value, new: &'a Value) -> JsonDiffTable<'a> {
+    JsonDiffTable::new(old, new)
+}
+
+/// The function converts a [`Table`] back to a [`Value`].
+///
+/// By default a first row is used as keys and each other row becomes an object,
+/// see [`JsonOptions`] for other options.
+///
+/// # Example
+///
+/// ```
+/// use json_to_table::{table_to_json, JsonOptions};
+/// use serde_json::json;
+/// use tabled::builder::Builder;
+///
+/// let mut builder = Builder::default();
+/// builder.set_columns(["name", "age", "married"]);
+/// builder.add_record(["John Doe", "43", "true"]);
+/// builder.add_record(["Jane Doe", "", "false"]);
+/// let table = builder.build();
+///
+/// let value = table_to_json(&table, JsonOptions::new().infer_types());
+///
+/// assert_eq!(
+///     value,
+///     json!([
+///         {"name": "John Doe", "age": 43, "married": true},
+///         {"name": "Jane Doe", "age": null, "married": false},
+///     ]),
+/// );
+/// ```
+///
+/// [`Table`]: tabled::Table
+pub fn table_to_json<R>(table: &tabled::Table<R>, opts: &JsonOptions) -> Value
+where
+    R: tabled::papergrid::records::Records,
+{
+    from_table::table_to_json(table, opts)
+}
+
+/// The function reads a newline-delimited json (NDJSON), where each line is an object.
+///
+/// Columns are a union of keys of all objects;
+/// see [`NdjsonTable`] for a bound on a number of rows and a streaming output.
+///
+/// # Example
+///
+/// ```
+/// use json_to_table::ndjson_to_table;
+///
+/// let input = concat!(
+///     "{\"name\": \"John Doe\", \"age\": 43}\n",
+///     "{\"name\": \"Jane Doe\", \"married\": true}\n",
+/// );
+///
+/// let table = ndjson_to_table(input.as_bytes()).build().unwrap().to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+-----+----------+---------+\n",
+///         "| age | name     | married |\n",
+///         "+-----+----------+---------+\n",
+///         "| 43  | John Doe |         |\n",
+///         "+-----+----------+---------+\n",
+///         "|     | Jane Doe | true    |\n",
+///         "+-----+----------+---------+",
+///     ),
+/// );
+/// ```
+pub fn ndjson_to_table<R>(reader: R) -> NdjsonTable<R>
+where
+    R: std::io::BufRead,
+{
+    NdjsonTable::new(reader)
+}
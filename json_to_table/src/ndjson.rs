@@ -0,0 +1,367 @@
+//! The module contains a [`NdjsonTable`] which builds a table out of a newline-delimited json.
+
+use std::{
+    collections::HashSet,
+    io::{self, BufRead, Write},
+};
+
+use serde_json::{Map, Value};
+use tabled::{
+    builder::Builder,
+    papergrid::util::{cut_str, string_width},
+    style::RawStyle,
+    Table,
+};
+
+const DEFAULT_SAMPLE_SIZE: usize = 100;
+
+/// Converter of a newline-delimited json (NDJSON) to a table.
+///
+/// Each line must be a json object.
+/// The columns of a table are a union of keys of all objects,
+/// in an order they were first seen
+/// (keys of a single object are in an order of a [`Map`]).
+/// Nested objects and arrays are rendered as a compact json.
+///
+/// See [`ndjson_to_table`].
+///
+/// [`ndjson_to_table`]: crate::ndjson_to_table
+#[derive(Debug, Clone)]
+pub struct NdjsonTable<R> {
+    reader: R,
+    max_rows: Option<usize>,
+    sample_size: usize,
+    style: Option<RawStyle>,
+}
+
+impl<R> NdjsonTable<R>
+where
+    R: BufRead,
+{
+    /// Creates a new [`NdjsonTable`] object.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            max_rows: None,
+            sample_size: DEFAULT_SAMPLE_SIZE,
+            style: None,
+        }
+    }
+
+    /// Set a maximum number of rows which will be read.
+    pub fn set_max_rows(&mut self, rows: usize) -> &mut Self {
+        self.max_rows = Some(rows);
+        self
+    }
+
+    /// Set a number of rows which are read before anything is printed in [`NdjsonTable::stream`].
+    ///
+    /// The columns and their widths are determined by the sample.
+    ///
+    /// Default is `100`, a sample can't be less than `1`.
+    pub fn set_sample_size(&mut self, rows: usize) -> &mut Self {
+        self.sample_size = rows.max(1);
+        self
+    }
+
+    /// Set a style which will be used,
+    /// default is [`Style::ascii`].
+    ///
+    /// [`Style::ascii`]: tabled::Style::ascii
+    pub fn set_style(&mut self, style: impl Into<RawStyle>) -> &mut Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    /// Reads all rows and builds a table out of them.
+    pub fn build(self) -> io::Result<Table> {
+        let mut objects = Objects::new(self.reader, self.max_rows);
+
+        let mut rows = Vec::new();
+        for object in &mut objects {
+            rows.push(object?);
+        }
+
+        let keys = collect_keys(&rows);
+        let rows = rows.iter().map(|object| object_to_row(object, &keys));
+
+        Ok(build_table(&keys, rows, self.style.as_ref()))
+    }
+
+    /// Prints rows to a given writer as soon as they are read.
+    ///
+    /// A first sample of rows is read to determine columns and their widths,
+    /// see [`NdjsonTable::set_sample_size`].
+    /// Keys which are not present in the sample are ignored in the rest of the rows,
+    /// and values which are wider than a column are truncated.
+    pub fn stream<W>(self, mut out: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let mut objects = Objects::new(self.reader, self.max_rows);
+        let style = self.style.as_ref();
+
+        let mut sample = Vec::new();
+        while sample.len() < self.sample_size {
+            match objects.next() {
+                Some(object) => sample.push(object?),
+                None => break,
+            }
+        }
+
+        let keys = collect_keys(&sample);
+        if keys.is_empty() {
+            // there's nothing to print, like in an empty table,
+            // though the rest of the rows are still checked
+            for object in objects {
+                object?;
+            }
+
+            return Ok(());
+        }
+
+        let header = keys.iter().map(|key| key.to_string()).collect::<Vec<_>>();
+        let sample = sample
+            .iter()
+            .map(|object| object_to_row(object, &keys))
+            .collect::<Vec<_>>();
+
+        let table = build_table(&keys, sample.iter().cloned(), style);
+        let mut lines = table
+            .to_string()
+            .lines()
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+
+        let bottom = lines.split_off(lines.len().saturating_sub(count_bottom_lines(&table)));
+        for line in lines {
+            writeln!(out, "{}", line)?;
+        }
+
+        let widths = (0..keys.len())
+            .map(|col| {
+                let header = string_width(&header[col]);
+                let cells = sample.iter().map(|row| max_line_width(&row[col]));
+                cells.fold(header, usize::max)
+            })
+            .collect::<Vec<_>>();
+
+        let separator = find_row_separator(&header, &widths, style);
+
+        for object in objects {
+            let row = fit_row(object_to_row(&object?, &keys), &widths);
+
+            // the row is rendered alone, and as its columns have fixed widths
+            // only its borders must be replaced by the ones it'd have in a whole table
+            let table = build_table::<String, _>(&[], [row], style);
+            let lines = table.to_string();
+            let lines = lines.lines().collect::<Vec<_>>();
+
+            let top = count_top_lines(&table);
+            let bottom = count_bottom_lines(&table);
+
+            if let Some(separator) = &separator {
+                writeln!(out, "{}", separator)?;
+            }
+
+            for line in &lines[top..lines.len().saturating_sub(bottom).max(top)] {
+                writeln!(out, "{}", line)?;
+            }
+
+            out.flush()?;
+        }
+
+        for line in bottom {
+            writeln!(out, "{}", line)?;
+        }
+
+        out.flush()
+    }
+}
+
+// finds a line which separates 2 rows (not a header) by rendering a table of blank rows
+fn find_row_separator(
+    header: &[String],
+    widths: &[usize],
+    style: Option<&RawStyle>,
+) -> Option<String> {
+    let blank = fit_row(vec![String::new(); widths.len()], widths);
+    let table = build_table(header, [blank.clone(), blank], style);
+
+    let cfg = table.get_config();
+    if !cfg.has_horizontal(2, 3) {
+        return None;
+    }
+
+    let header_height = header
+        .iter()
+        .map(|key| key.lines().count())
+        .max()
+        .unwrap_or(0);
+    let index =
+        count_top_lines(&table) + header_height.max(1) + usize::from(cfg.has_horizontal(1, 3)) + 1;
+
+    table.to_string().lines().nth(index).map(str::to_owned)
+}
+
+struct Objects<R> {
+    reader: R,
+    max_rows: Option<usize>,
+    count_rows: usize,
+    count_lines: usize,
+    buf: String,
+}
+
+impl<R> Objects<R> {
+    fn new(reader: R, max_rows: Option<usize>) -> Self {
+        Self {
+            reader,
+            max_rows,
+            count_rows: 0,
+            count_lines: 0,
+            buf: String::new(),
+        }
+    }
+}
+
+impl<R> Iterator for Objects<R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<Map<String, Value>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if matches!(self.max_rows, Some(max) if self.count_rows >= max) {
+            return None;
+        }
+
+        loop {
+            self.buf.clear();
+            match self.reader.read_line(&mut self.buf) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(err) => return Some(Err(err)),
+            }
+
+            self.count_lines += 1;
+
+            let line = self.buf.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            self.count_rows += 1;
+
+            let object = match serde_json::from_str(line) {
+                Ok(Value::Object(map)) => Ok(map),
+                Ok(_) => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {} is not a json object", self.count_lines),
+                )),
+                Err(err) => Err(err.into()),
+            };
+
+            return Some(object);
+        }
+    }
+}
+
+fn collect_keys(objects: &[Map<String, Value>]) -> Vec<&str> {
+    let mut keys: Vec<&str> = Vec::new();
+    let mut seen = HashSet::new();
+    for object in objects {
+        for key in object.keys() {
+            if seen.insert(key.as_str()) {
+                keys.push(key);
+            }
+        }
+    }
+
+    keys
+}
+
+fn object_to_row(object: &Map<String, Value>, keys: &[&str]) -> Vec<String> {
+    keys.iter()
+        .map(|key| match object.get(*key) {
+            Some(Value::String(text)) => text.clone(),
+            Some(Value::Null) | None => String::new(),
+            Some(value) => value.to_string(),
+        })
+        .collect()
+}
+
+fn build_table<H, I>(header: &[H], rows: I, style: Option<&RawStyle>) -> Table
+where
+    H: AsRef<str>,
+    I: IntoIterator<Item = Vec<String>>,
+{
+    let mut builder = Builder::new();
+    if !header.is_empty() {
+        builder.set_columns(header.iter().map(|key| key.as_ref().to_owned()));
+    }
+
+    for row in rows {
+        builder.add_record(row);
+    }
+
+    let mut table = builder.build();
+    if let Some(style) = style {
+        table.with(style);
+    }
+
+    table
+}
+
+fn count_top_lines(table: &Table) -> usize {
+    let count_rows = table.get_records().count_rows();
+    let has_top = count_rows > 0 && table.get_config().has_horizontal(0, count_rows);
+
+    usize::from(has_top)
+}
+
+fn count_bottom_lines(table: &Table) -> usize {
+    let count_rows = table.get_records().count_rows();
+    let has_bottom = count_rows > 0 && table.get_config().has_horizontal(count_rows, count_rows);
+
+    usize::from(has_bottom)
+}
+
+fn max_line_width(text: &str) -> usize {
+    text.lines().map(string_width).max().unwrap_or(0)
+}
+
+fn fit_row(row: Vec<String>, widths: &[usize]) -> Vec<String> {
+    row.into_iter()
+        .zip(widths)
+        .map(|(text, &width)| fit_text(&text, width))
+        .collect()
+}
+
+// makes each line of a text to have exactly a given width
+fn fit_text(text: &str, width: usize) -> String {
+    let lines = if text.is_empty() {
+        vec![""]
+    } else {
+        text.lines().collect()
+    };
+
+    let mut buf = String::new();
+    for (i, line) in lines.into_iter().enumerate() {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        let line_width = string_width(line);
+        if line_width > width {
+            if width > 0 {
+                buf.push_str(&cut_str(line, width - 1));
+                buf.push('…');
+            }
+        } else {
+            buf.push_str(line);
+            buf.extend(std::iter::repeat(' ').take(width - line_width));
+        }
+    }
+
+    buf
+}
@@ -0,0 +1,295 @@
+//! The module contains a [`Selector`] which is used to pick a part of a value.
+//!
+//! It supports a [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) and
+//! a small subset of a `JSONPath`: `$`, `.key`, `['key']`, `[0]`, `.*` and `[*]`.
+
+use std::fmt::Write;
+
+use crate::value::{Node, NodeKind, TableValue};
+
+/// Selector of a sub values of a value.
+#[derive(Debug, Clone)]
+pub(crate) struct Selector {
+    kind: SelectorKind,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelectorKind {
+    Pointer,
+    Path,
+    // a selector which was failed to be parsed
+    Invalid,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Name(String),
+    Index(usize),
+    Wildcard,
+}
+
+#[derive(Debug, Clone)]
+enum PathPart {
+    Key(String),
+    Index(usize),
+}
+
+impl Selector {
+    /// Parses either a JSON Pointer or a JSONPath.
+    ///
+    /// A path which can't be parsed doesn't match anything.
+    pub(crate) fn parse(path: &str) -> Self {
+        let result = if path.is_empty() || path.starts_with('/') {
+            parse_pointer(path).map(|segments| (SelectorKind::Pointer, segments))
+        } else {
+            parse_path(path).map(|segments| (SelectorKind::Path, segments))
+        };
+
+        match result {
+            Some((kind, segments)) => Self { kind, segments },
+            None => Self {
+                kind: SelectorKind::Invalid,
+                segments: Vec::new(),
+            },
+        }
+    }
+
+    /// Checks whether a selector can match at most one value.
+    pub(crate) fn is_definite(&self) -> bool {
+        !self
+            .segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::Wildcard))
+    }
+
+    /// Returns all matched values together with their paths.
+    pub(crate) fn find<'v, V>(&self, value: &Node<'v, V>) -> Vec<(String, Node<'v, V>)>
+    where
+        V: TableValue,
+    {
+        let mut matches = Vec::new();
+        if self.kind != SelectorKind::Invalid {
+            let mut path = Vec::new();
+            self.find_r(value.clone(), 0, &mut path, &mut matches);
+        }
+
+        matches
+    }
+
+    fn find_r<'v, V>(
+        &self,
+        value: Node<'v, V>,
+        index: usize,
+        path: &mut Vec<PathPart>,
+        matches: &mut Vec<(String, Node<'v, V>)>,
+    ) where
+        V: TableValue,
+    {
+        let segment = match self.segments.get(index) {
+            Some(segment) => segment,
+            None => {
+                matches.push((self.format_path(path), value));
+                return;
+            }
+        };
+
+        match (segment, value.kind()) {
+            (Segment::Name(name), NodeKind::Object(map)) => {
+                if let Some((key, value)) = map.into_iter().find(|(key, _)| key == name) {
+                    path.push(PathPart::Key(key.into_owned()));
+                    self.find_r(value, index + 1, path, matches);
+                    path.pop();
+                }
+            }
+            (Segment::Name(name), NodeKind::Array(list, _)) => {
+                let value = name
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|i| list.into_iter().nth(i).map(|v| (i, v)));
+                if let Some((i, value)) = value {
+                    path.push(PathPart::Index(i));
+                    self.find_r(value, index + 1, path, matches);
+                    path.pop();
+                }
+            }
+            (Segment::Index(i), NodeKind::Array(list, _)) => {
+                if let Some(value) = list.into_iter().nth(*i) {
+                    path.push(PathPart::Index(*i));
+                    self.find_r(value, index + 1, path, matches);
+                    path.pop();
+                }
+            }
+            (Segment::Wildcard, NodeKind::Object(map)) => {
+                for (key, value) in map {
+                    path.push(PathPart::Key(key.into_owned()));
+                    self.find_r(value, index + 1, path, matches);
+                    path.pop();
+                }
+            }
+            (Segment::Wildcard, NodeKind::Array(list, _)) => {
+                for (i, value) in list.into_iter().enumerate() {
+                    path.push(PathPart::Index(i));
+                    self.find_r(value, index + 1, path, matches);
+                    path.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn format_path(&self, path: &[PathPart]) -> String {
+        let mut buf = String::new();
+
+        match self.kind {
+            SelectorKind::Pointer => {
+                for part in path {
+                    match part {
+                        PathPart::Key(key) => {
+                            buf.push('/');
+                            buf.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                        }
+                        PathPart::Index(i) => {
+                            let _ = write!(buf, "/{}", i);
+                        }
+                    }
+                }
+
+                if buf.is_empty() {
+                    buf.push('/');
+                }
+            }
+            SelectorKind::Path | SelectorKind::Invalid => {
+                buf.push('$');
+
+                for part in path {
+                    match part {
+                        PathPart::Key(key) if is_identifier(key) => {
+                            buf.push('.');
+                            buf.push_str(key);
+                        }
+                        PathPart::Key(key) => {
+                            let _ = write!(buf, "['{}']", key.replace('\'', "\\'"));
+                        }
+                        PathPart::Index(i) => {
+                            let _ = write!(buf, "[{}]", i);
+                        }
+                    }
+                }
+            }
+        }
+
+        buf
+    }
+}
+
+fn is_identifier(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Appends a key to a path in a form of `address.city` or `address["zip code"]`.
+pub(crate) fn push_key(path: &mut String, key: &str) {
+    if !is_identifier(key) {
+        let _ = write!(path, "[{:?}]", key);
+    } else if path.is_empty() {
+        path.push_str(key);
+    } else {
+        path.push('.');
+        path.push_str(key);
+    }
+}
+
+/// Appends an index to a path in a form of `phones[1]`.
+pub(crate) fn push_index(path: &mut String, index: usize) {
+    let _ = write!(path, "[{}]", index);
+}
+
+fn parse_pointer(path: &str) -> Option<Vec<Segment>> {
+    if path.is_empty() {
+        return Some(Vec::new());
+    }
+
+    path.split('/')
+        .skip(1)
+        .map(|token| {
+            if token.contains('~') && !is_valid_pointer_escape(token) {
+                return None;
+            }
+
+            let token = token.replace("~1", "/").replace("~0", "~");
+            Some(Segment::Name(token))
+        })
+        .collect()
+}
+
+fn is_valid_pointer_escape(token: &str) -> bool {
+    let mut chars = token.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '~' && !matches!(chars.next(), Some('0') | Some('1')) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn parse_path(path: &str) -> Option<Vec<Segment>> {
+    let mut rest = path.strip_prefix('$')?;
+    let mut segments = Vec::new();
+
+    while !rest.is_empty() {
+        if let Some(tail) = rest.strip_prefix('.') {
+            let end = tail.find(['.', '[']).unwrap_or(tail.len());
+            let name = &tail[..end];
+            let segment = match name {
+                "" => return None,
+                "*" => Segment::Wildcard,
+                name => Segment::Name(name.to_owned()),
+            };
+
+            segments.push(segment);
+            rest = &tail[end..];
+        } else if let Some(tail) = rest.strip_prefix('[') {
+            let (segment, tail) = parse_brackets(tail)?;
+            segments.push(segment);
+            rest = tail;
+        } else {
+            return None;
+        }
+    }
+
+    Some(segments)
+}
+
+fn parse_brackets(text: &str) -> Option<(Segment, &str)> {
+    if let Some(tail) = text.strip_prefix("*]") {
+        return Some((Segment::Wildcard, tail));
+    }
+
+    let quote = text.chars().next()?;
+    if quote == '\'' || quote == '"' {
+        let text = &text[1..];
+        let mut name = String::new();
+        let mut chars = text.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => name.push(chars.next()?.1),
+                c if c == quote => {
+                    let tail = text[i + 1..].strip_prefix(']')?;
+                    return Some((Segment::Name(name), tail));
+                }
+                c => name.push(c),
+            }
+        }
+
+        return None;
+    }
+
+    let end = text.find(']')?;
+    let index = text[..end].trim().parse::<usize>().ok()?;
+
+    Some((Segment::Index(index), &text[end + 1..]))
+}
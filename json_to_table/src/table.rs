@@ -1,35 +1,67 @@
-use core::fmt::{self, Display};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+};
+use std::collections::BTreeMap;
 
 use serde_json::Value;
 use tabled::{papergrid::GridConfig, style::RawStyle, Style, Table};
 
+use crate::{
+    select::{push_index, push_key, Selector},
+    value::{Limits, Node, NodeKind, TableValue},
+};
+
+#[cfg(feature = "color")]
+use crate::theme::Theme;
+
 /// Converter of [`Value`] to a table,
 /// with a set of configurations.
+///
+/// Any [`TableValue`] can be used instead of a [`Value`].
 #[derive(Debug, Clone)]
-pub struct JsonTable<'val, ModeVisitor = fn(&Value) -> Orientation> {
-    value: &'val Value,
+pub struct JsonTable<'val, ModeVisitor = fn(&Value) -> Orientation, V = Value> {
+    value: &'val V,
     cfg: Config,
     mode_visitor: Option<ModeVisitor>,
 }
 
-impl JsonTable<'_> {
+impl<'val, V> JsonTable<'val, fn(&V) -> Orientation, V>
+where
+    V: TableValue,
+{
     /// Creates a new [`JsonTable`] object.
-    pub fn new(value: &Value) -> JsonTable<'_> {
+    pub fn new(value: &'val V) -> Self {
         JsonTable {
             value,
             cfg: Config {
                 plain: true,
+                flat: false,
+                show_types: false,
                 style: None,
                 cfg: None,
                 array_orientation: Orientation::Vertical,
                 object_orientation: Orientation::Vertical,
+                columnar: false,
+                missing_value: String::new(),
+                max_depth: None,
+                max_array_length: None,
+                max_string_length: None,
+                selector: None,
+                show_paths: false,
+                keys_order: KeysOrder::Insertion,
+                pinned_keys: Vec::new(),
+                depth_styles: BTreeMap::new(),
+                depth_paddings: BTreeMap::new(),
+                #[cfg(feature = "color")]
+                theme: None,
             },
             mode_visitor: None,
         }
     }
 }
 
-impl<'val, ModeVisitor> JsonTable<'val, ModeVisitor> {
+impl<'val, ModeVisitor, V> JsonTable<'val, ModeVisitor, V> {
     /// Set a style which will be used,
     /// default is [`Style::ascii`].
     pub fn set_style(&mut self, style: impl Into<RawStyle>) -> &mut Self {
@@ -37,12 +69,126 @@ impl<'val, ModeVisitor> JsonTable<'val, ModeVisitor> {
         self
     }
 
+    /// Set a style which will be used for tables on a given nesting depth.
+    ///
+    /// The outer table has a depth `0`.
+    /// It overrides a style set by [`JsonTable::set_style`].
+    ///
+    /// BE AWARE: The setting works only in not collapsed mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use json_to_table::json_to_table;
+    /// use serde_json::json;
+    /// use tabled::Style;
+    ///
+    /// let value = json!({"name": "John Doe", "phones": ["+44 1234567", "+44 2345678"]});
+    ///
+    /// let table = json_to_table(&value)
+    ///     .set_depth_style(1, Style::modern())
+    ///     .set_depth_padding(2, 0, 0, 0, 0)
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+--------+-----------------+\n",
+    ///         "| name   |  John Doe       |\n",
+    ///         "+--------+-----------------+\n",
+    ///         "| phones | ┌─────────────┐ |\n",
+    ///         "|        | │ +44 1234567 │ |\n",
+    ///         "|        | ├─────────────┤ |\n",
+    ///         "|        | │ +44 2345678 │ |\n",
+    ///         "|        | └─────────────┘ |\n",
+    ///         "+--------+-----------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn set_depth_style(&mut self, depth: usize, style: impl Into<RawStyle>) -> &mut Self {
+        self.cfg.depth_styles.insert(depth, style.into());
+        self
+    }
+
+    /// Set a padding which will be used for tables on a given nesting depth.
+    ///
+    /// Notice that a scalar value is a table on its own,
+    /// so its padding is set on a depth next to the one of its object or array.
+    ///
+    /// BE AWARE: The setting works only in not collapsed mode.
+    pub fn set_depth_padding(
+        &mut self,
+        depth: usize,
+        left: usize,
+        right: usize,
+        top: usize,
+        bottom: usize,
+    ) -> &mut Self {
+        self.cfg
+            .depth_paddings
+            .insert(depth, [left, right, top, bottom]);
+        self
+    }
+
     /// Collapse tables out instead of tables within tables.
     pub fn collapse(&mut self) -> &mut Self {
         self.cfg.plain = false;
         self
     }
 
+    /// Flatten a value into a list of leaf values keyed by their paths,
+    /// like `address.city` or `phones[1]`.
+    ///
+    /// The orientation of the list is set by [`JsonTable::set_object_mode`]
+    /// or [`JsonTable::set_array_mode`] depending on a root value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use json_to_table::json_to_table;
+    /// use serde_json::json;
+    ///
+    /// let value = json!({
+    ///     "name": "John Doe",
+    ///     "address": {
+    ///         "street": "10 Downing Street",
+    ///         "city": "London"
+    ///     },
+    ///     "phones": ["+44 1234567", "+44 2345678"],
+    /// });
+    ///
+    /// let table = json_to_table(&value).flatten().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+----------------+-------------------+\n",
+    ///         "| address.city   | London            |\n",
+    ///         "+----------------+-------------------+\n",
+    ///         "| address.street | 10 Downing Street |\n",
+    ///         "+----------------+-------------------+\n",
+    ///         "| name           | John Doe          |\n",
+    ///         "+----------------+-------------------+\n",
+    ///         "| phones[0]      | +44 1234567       |\n",
+    ///         "+----------------+-------------------+\n",
+    ///         "| phones[1]      | +44 2345678       |\n",
+    ///         "+----------------+-------------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn flatten(&mut self) -> &mut Self {
+        self.cfg.flat = true;
+        self
+    }
+
+    /// Show a type of each value in a flattened table.
+    ///
+    /// See [`JsonTable::flatten`].
+    pub fn show_types(&mut self) -> &mut Self {
+        self.cfg.show_types = true;
+        self
+    }
+
     /// Set a table mode for a [`serde_json::Value::Object`].
     ///
     /// BE AWARE: The setting works only in not collapsed mode.
@@ -59,12 +205,255 @@ impl<'val, ModeVisitor> JsonTable<'val, ModeVisitor> {
         self
     }
 
+    /// Render arrays which consist only of objects as a single table,
+    /// where each object is a row and each key is a column.
+    ///
+    /// Nested values are rendered recursively inside the cells.
+    /// Columns are ordered according to [`JsonTable::set_keys_order`] and [`JsonTable::set_pinned_keys`].
+    ///
+    /// BE AWARE: The setting works only in not collapsed mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use json_to_table::json_to_table;
+    /// use serde_json::json;
+    ///
+    /// let value = json!([
+    ///     {"name": "Aleix Melon", "id": "E00245"},
+    ///     {"name": "Bob Washington", "age": 34},
+    /// ]);
+    ///
+    /// let table = json_to_table(&value).columnar().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+----------+------------------+------+\n",
+    ///         "| id       | name             | age  |\n",
+    ///         "+----------+------------------+------+\n",
+    ///         "|  E00245  |  Aleix Melon     |      |\n",
+    ///         "+----------+------------------+------+\n",
+    ///         "|          |  Bob Washington  |  34  |\n",
+    ///         "+----------+------------------+------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn columnar(&mut self) -> &mut Self {
+        self.cfg.columnar = true;
+        self
+    }
+
+    /// Set a text which is used in a columnar table when an object has no such key.
+    ///
+    /// Default is an empty string.
+    ///
+    /// See [`JsonTable::columnar`].
+    pub fn set_missing_value(&mut self, text: impl Into<String>) -> &mut Self {
+        self.cfg.missing_value = text.into();
+        self
+    }
+
+    /// Set an order in which keys of objects are rendered.
+    ///
+    /// It's also an order of columns of a columnar table.
+    ///
+    /// Default is [`KeysOrder::Insertion`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use json_to_table::{json_to_table, KeysOrder};
+    /// use serde_json::json;
+    ///
+    /// let value = json!({"a": 1, "bb": 2, "ccc": 3});
+    ///
+    /// let table = json_to_table(&value)
+    ///     .set_keys_order(KeysOrder::Custom(|a, b| b.len().cmp(&a.len())))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-----+-----+\n",
+    ///         "| ccc |  3  |\n",
+    ///         "+-----+-----+\n",
+    ///         "| bb  |  2  |\n",
+    ///         "+-----+-----+\n",
+    ///         "| a   |  1  |\n",
+    ///         "+-----+-----+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn set_keys_order(&mut self, order: KeysOrder) -> &mut Self {
+        self.cfg.keys_order = order;
+        self
+    }
+
+    /// Set keys which are rendered before any other keys, in a given order.
+    ///
+    /// The rest of the keys are ordered according to [`JsonTable::set_keys_order`].
+    /// In a columnar table the pinned keys are the first columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use json_to_table::json_to_table;
+    /// use serde_json::json;
+    ///
+    /// let value = json!({"age": 43, "id": 1, "name": "John Doe"});
+    ///
+    /// let table = json_to_table(&value).set_pinned_keys(["id", "name"]).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+------------+\n",
+    ///         "| id   |  1         |\n",
+    ///         "+------+------------+\n",
+    ///         "| name |  John Doe  |\n",
+    ///         "+------+------------+\n",
+    ///         "| age  |  43        |\n",
+    ///         "+------+------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn set_pinned_keys<I, S>(&mut self, keys: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.cfg.pinned_keys = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set a maximum depth of nested objects and arrays which will be rendered.
+    ///
+    /// Objects and arrays deeper than the limit are replaced by a summary,
+    /// like `{…3 keys}` or `[…12 items]`.
+    /// The root value has a depth `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use json_to_table::json_to_table;
+    /// use serde_json::json;
+    ///
+    /// let value = json!({
+    ///     "name": "John Doe",
+    ///     "address": {
+    ///         "street": "10 Downing Street",
+    ///         "city": "London"
+    ///     },
+    ///     "phones": ["+44 1234567", "+44 2345678", "+44 3456789"],
+    /// });
+    ///
+    /// let table = json_to_table(&value).max_depth(1).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+---------+--------------+\n",
+    ///         "| address |  {…2 keys}   |\n",
+    ///         "+---------+--------------+\n",
+    ///         "| name    |  John Doe    |\n",
+    ///         "+---------+--------------+\n",
+    ///         "| phones  |  […3 items]  |\n",
+    ///         "+---------+--------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+        self.cfg.max_depth = Some(depth);
+        self
+    }
+
+    /// Set a maximum number of elements of an array which will be rendered.
+    ///
+    /// The rest of the elements are replaced by a marker, like `…2 more`,
+    /// which is a footer row of a columnar table.
+    pub fn max_array_length(&mut self, length: usize) -> &mut Self {
+        self.cfg.max_array_length = Some(length);
+        self
+    }
+
+    /// Set a maximum number of characters of a string which will be rendered.
+    ///
+    /// A truncated string ends with a `…` marker.
+    pub fn max_string_length(&mut self, length: usize) -> &mut Self {
+        self.cfg.max_string_length = Some(length);
+        self
+    }
+
+    /// Render only a part of a value, which is found by a given path.
+    ///
+    /// The path can be either a [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901), like `/data/items/0`,
+    /// or a simple `JSONPath`, like `$.items[*].name`.
+    /// Supported `JSONPath` elements are `$`, `.key`, `['key']`, `[0]`, `.*` and `[*]`.
+    ///
+    /// If a path may match many values (has a wildcard) they are rendered as a list.
+    /// A path which can't be parsed doesn't match anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use json_to_table::json_to_table;
+    /// use serde_json::json;
+    ///
+    /// let value = json!({
+    ///     "data": {
+    ///         "items": [
+    ///             {"name": "Aleix Melon", "id": "E00245"},
+    ///             {"name": "Bob Washington", "id": "E01245"},
+    ///         ]
+    ///     }
+    /// });
+    ///
+    /// let table = json_to_table(&value)
+    ///     .select("$.data.items[*].name")
+    ///     .show_paths()
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+----------------------+------------------+\n",
+    ///         "| $.data.items[0].name |  Aleix Melon     |\n",
+    ///         "+----------------------+------------------+\n",
+    ///         "| $.data.items[1].name |  Bob Washington  |\n",
+    ///         "+----------------------+------------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn select(&mut self, path: &str) -> &mut Self {
+        self.cfg.selector = Some(Selector::parse(path));
+        self
+    }
+
+    /// Show paths of selected values in a left column.
+    ///
+    /// See [`JsonTable::select`].
+    pub fn show_paths(&mut self) -> &mut Self {
+        self.cfg.show_paths = true;
+        self
+    }
+
+    /// Set a theme which colors keys and values by their types
+    /// and borders of nested tables by their depth.
+    ///
+    /// See [`Theme`].
+    #[cfg(feature = "color")]
+    pub fn set_theme(&mut self, theme: Theme) -> &mut Self {
+        self.cfg.theme = Some(theme);
+        self
+    }
+
     /// Set a visitor which can configure table mode at processing time.
     ///
     /// BE AWARE: The setting works only in not collapsed mode.
-    pub fn set_mode_visitor<F>(self, visitor: F) -> JsonTable<'val, F>
+    pub fn set_mode_visitor<F>(self, visitor: F) -> JsonTable<'val, F, V>
     where
-        F: FnMut(&Value) -> Orientation,
+        F: FnMut(&V) -> Orientation,
     {
         JsonTable {
             cfg: self.cfg,
@@ -134,9 +523,10 @@ impl<'val, ModeVisitor> JsonTable<'val, ModeVisitor> {
     }
 }
 
-impl<ModeVisitor> Display for JsonTable<'_, ModeVisitor>
+impl<ModeVisitor, V> Display for JsonTable<'_, ModeVisitor, V>
 where
-    ModeVisitor: FnMut(&Value) -> Orientation + Clone,
+    ModeVisitor: FnMut(&V) -> Orientation + Clone,
+    V: TableValue,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut mode_visitor = self.mode_visitor.clone();
@@ -145,11 +535,12 @@ where
     }
 }
 
-impl<ModeVisitor> From<JsonTable<'_, ModeVisitor>> for Table
+impl<ModeVisitor, V> From<JsonTable<'_, ModeVisitor, V>> for Table
 where
-    ModeVisitor: FnMut(&Value) -> Orientation,
+    ModeVisitor: FnMut(&V) -> Orientation,
+    V: TableValue,
 {
-    fn from(mut t: JsonTable<'_, ModeVisitor>) -> Self {
+    fn from(mut t: JsonTable<'_, ModeVisitor, V>) -> Self {
         json_to_table::json_to_table(t.value, &t.cfg, t.mode_visitor.as_mut())
     }
 }
@@ -157,10 +548,37 @@ where
 #[derive(Debug, Clone)]
 struct Config {
     plain: bool,
+    flat: bool,
+    show_types: bool,
     style: Option<RawStyle>,
     cfg: Option<GridConfig>,
     object_orientation: Orientation,
     array_orientation: Orientation,
+    columnar: bool,
+    missing_value: String,
+    max_depth: Option<usize>,
+    max_array_length: Option<usize>,
+    max_string_length: Option<usize>,
+    selector: Option<Selector>,
+    show_paths: bool,
+    keys_order: KeysOrder,
+    pinned_keys: Vec<String>,
+    depth_styles: BTreeMap<usize, RawStyle>,
+    depth_paddings: BTreeMap<usize, [usize; 4]>,
+    #[cfg(feature = "color")]
+    theme: Option<Theme>,
+}
+
+/// Returns a short description of an object, like `{…3 keys}`.
+pub(crate) fn summarize_object(length: usize) -> String {
+    let suffix = if length == 1 { "key" } else { "keys" };
+    format!("{{…{} {}}}", length, suffix)
+}
+
+/// Returns a short description of an array, like `[…12 items]`.
+pub(crate) fn summarize_array(length: usize) -> String {
+    let suffix = if length == 1 { "item" } else { "items" };
+    format!("[…{} {}]", length, suffix)
 }
 
 /// The structure represents a table mode for a given entity,
@@ -173,58 +591,282 @@ pub enum Orientation {
     Horizontal,
 }
 
+/// The structure represents an order of keys of an object.
+///
+/// See [`JsonTable::set_keys_order`].
+#[derive(Debug, Clone, Copy)]
+pub enum KeysOrder {
+    /// Keys are ordered as they are stored in a map.
+    ///
+    /// For a [`Value`] it's an insertion order when a `preserve_order` feature of `serde_json` is on,
+    /// otherwise it's an alphabetical order.
+    ///
+    /// Columns of a columnar table are ordered as their keys are first seen in a list of objects.
+    Insertion,
+    /// Keys are ordered alphabetically.
+    Alphabetical,
+    /// Keys are ordered by a given comparator.
+    Custom(fn(&str, &str) -> Ordering),
+}
+
 mod json_to_table {
     #![allow(clippy::too_many_arguments)]
 
-    use std::cmp;
+    use std::{
+        borrow::Cow,
+        cmp,
+        collections::{HashMap, HashSet},
+    };
 
     use tabled::{
         builder::Builder,
         col,
-        papergrid::{records::Records, util::string_width_multiline},
-        Height, Padding, TableOption, Width,
+        object::Cell,
+        papergrid::{records::Records, util::string_width_multiline, BorderSymbol},
+        Height, Modify, Padding, Span, TableOption, Width,
     };
 
     use super::*;
 
-    pub(super) fn json_to_table<F>(
-        value: &Value,
+    pub(super) fn json_to_table<V, F>(
+        value: &V,
         cfg: &Config,
         mut mode_visitor: Option<&mut F>,
     ) -> Table
     where
-        F: FnMut(&Value) -> Orientation,
+        V: TableValue,
+        F: FnMut(&V) -> Orientation,
+    {
+        let value = Node::new(value);
+
+        match cfg.selector.as_ref() {
+            Some(selector) => selection_to_table(&value, selector, cfg, &mut mode_visitor),
+            None => value_to_table(value, cfg, &mut mode_visitor, 0),
+        }
+    }
+
+    fn value_to_table<V, F>(
+        value: Node<'_, V>,
+        cfg: &Config,
+        mode_visitor: &mut Option<&mut F>,
+        depth: usize,
+    ) -> Table
+    where
+        V: TableValue,
+        F: FnMut(&V) -> Orientation,
     {
-        if cfg.plain {
-            json_to_table_f(value, cfg, &mut mode_visitor, true)
+        let value = value.limited(Limits {
+            max_depth: cfg.max_depth,
+            max_array_length: cfg.max_array_length,
+            max_string_length: cfg.max_string_length,
+        });
+
+        if cfg.flat {
+            json_to_table_flat(&value, cfg, mode_visitor)
+        } else if cfg.plain {
+            json_to_table_f(&value, cfg, mode_visitor, depth)
         } else {
-            json_to_table_r(value, cfg, 0, 0, true, true, false, false, &[], None)
+            json_to_table_r(&value, cfg, 0, 0, true, true, false, false, &[], None)
         }
     }
 
-    fn json_to_table_f<F>(
-        v: &Value,
+    fn json_to_table_flat<V, F>(
+        value: &Node<'_, V>,
+        cfg: &Config,
+        mode_visitor: &mut Option<&mut F>,
+    ) -> Table
+    where
+        V: TableValue,
+        F: FnMut(&V) -> Orientation,
+    {
+        let mut leafs = Vec::new();
+        collect_leafs(value, cfg, &mut String::new(), &mut leafs);
+
+        let orientation = match value.kind() {
+            NodeKind::Array(..) => get_orientation(value, cfg.array_orientation, mode_visitor),
+            _ => get_orientation(value, cfg.object_orientation, mode_visitor),
+        };
+
+        let mut builder = Builder::new();
+
+        match orientation {
+            Orientation::Vertical => {
+                for (path, value) in &leafs {
+                    let mut row = vec![
+                        key_text(path, cfg),
+                        value_text(value, &value_to_string(value), cfg),
+                    ];
+                    if cfg.show_types {
+                        row.push(value_text(value, value_type(value), cfg));
+                    }
+
+                    builder.add_record(row);
+                }
+            }
+            Orientation::Horizontal => {
+                let mut paths = Vec::with_capacity(leafs.len());
+                let mut values = Vec::with_capacity(leafs.len());
+                let mut types = Vec::with_capacity(leafs.len());
+                for (path, value) in &leafs {
+                    paths.push(key_text(path, cfg));
+                    values.push(value_text(value, &value_to_string(value), cfg));
+                    types.push(value_text(value, value_type(value), cfg));
+                }
+
+                builder.hint_column_size(paths.len());
+                builder.add_record(paths);
+                builder.add_record(values);
+
+                if cfg.show_types {
+                    builder.add_record(types);
+                }
+            }
+        }
+
+        let mut table = builder.build();
+        set_table_style(&mut table, cfg, 0);
+
+        table
+    }
+
+    fn collect_leafs<'v, V>(
+        value: &Node<'v, V>,
+        cfg: &Config,
+        path: &mut String,
+        leafs: &mut Vec<(String, NodeKind<'v, V>)>,
+    ) where
+        V: TableValue,
+    {
+        match value.kind() {
+            NodeKind::Object(map) if !map.is_empty() => {
+                for (key, value) in ordered_entries(&map, cfg) {
+                    let length = path.len();
+                    push_key(path, key);
+                    collect_leafs(value, cfg, path, leafs);
+                    path.truncate(length);
+                }
+            }
+            NodeKind::Array(list, more) if !list.is_empty() || more > 0 => {
+                let marker = Node::more_marker(more);
+                for (i, value) in list.iter().chain(&marker).enumerate() {
+                    let length = path.len();
+                    push_index(path, i);
+                    collect_leafs(value, cfg, path, leafs);
+                    path.truncate(length);
+                }
+            }
+            kind => leafs.push((path.clone(), kind)),
+        }
+    }
+
+    fn value_to_string<V>(value: &NodeKind<'_, V>) -> String {
+        match value {
+            NodeKind::String(text) => text.to_string(),
+            NodeKind::Bool(val) => val.to_string(),
+            NodeKind::Number(num) => num.to_string(),
+            NodeKind::Object(_) => String::from("{}"),
+            NodeKind::Array(..) => String::from("[]"),
+            NodeKind::Null => String::new(),
+        }
+    }
+
+    fn value_type<V>(value: &NodeKind<'_, V>) -> &'static str {
+        match value {
+            NodeKind::Null => "null",
+            NodeKind::Bool(_) => "bool",
+            NodeKind::Number(_) => "number",
+            NodeKind::String(_) => "string",
+            NodeKind::Array(..) => "array",
+            NodeKind::Object(_) => "object",
+        }
+    }
+
+    fn selection_to_table<V, F>(
+        value: &Node<'_, V>,
+        selector: &Selector,
+        cfg: &Config,
+        mode_visitor: &mut Option<&mut F>,
+    ) -> Table
+    where
+        V: TableValue,
+        F: FnMut(&V) -> Orientation,
+    {
+        let selected = selector.find(value);
+
+        if cfg.show_paths {
+            let mut builder = Builder::new();
+            for (path, value) in selected {
+                let value = value_to_table(value, cfg, mode_visitor, 1).to_string();
+                builder.add_record([path, value]);
+            }
+
+            let mut table = builder.build();
+            set_table_style(&mut table, cfg, 0);
+
+            return table;
+        }
+
+        if selector.is_definite() {
+            return match selected.into_iter().next() {
+                Some((_, value)) => value_to_table(value, cfg, mode_visitor, 0),
+                None => Builder::new().build(),
+            };
+        }
+
+        let list = selected.into_iter().map(|(_, v)| v).collect();
+        let list = Node::synthetic(NodeKind::Array(list, 0));
+        value_to_table(list, cfg, mode_visitor, 0)
+    }
+
+    fn get_orientation<V, F>(
+        value: &Node<'_, V>,
+        default: Orientation,
+        mode_visitor: &mut Option<&mut F>,
+    ) -> Orientation
+    where
+        V: TableValue,
+        F: FnMut(&V) -> Orientation,
+    {
+        match (mode_visitor.as_mut(), value.origin()) {
+            (Some(f), Some(origin)) => (f)(origin),
+            _ => default,
+        }
+    }
+
+    fn json_to_table_f<V, F>(
+        v: &Node<'_, V>,
         config: &Config,
         mode_visitor: &mut Option<&mut F>,
-        outer: bool,
+        depth: usize,
     ) -> Table
     where
-        F: FnMut(&Value) -> Orientation,
+        V: TableValue,
+        F: FnMut(&V) -> Orientation,
     {
-        match v {
-            Value::Array(arr) => {
+        let kind = v.kind();
+
+        if config.columnar {
+            if let NodeKind::Array(arr, more) = &kind {
+                if let Some(objects) = list_of_objects(arr) {
+                    return json_to_table_columnar(&objects, *more, config, mode_visitor, depth);
+                }
+            }
+        }
+
+        match &kind {
+            NodeKind::Array(arr, more) => {
+                let marker = Node::more_marker(*more);
+                let arr = arr.iter().chain(&marker).collect::<Vec<_>>();
+
                 let mut builder = Builder::new();
 
-                let orientation = mode_visitor
-                    .as_mut()
-                    .map(|f| (f)(v))
-                    .unwrap_or(config.array_orientation);
+                let orientation = get_orientation(v, config.array_orientation, mode_visitor);
 
                 match orientation {
                     Orientation::Vertical => {
                         for value in arr {
                             let val =
-                                json_to_table_f(value, config, mode_visitor, false).to_string();
+                                json_to_table_f(value, config, mode_visitor, depth + 1).to_string();
                             builder.add_record([val]);
                         }
                     }
@@ -232,7 +874,7 @@ mod json_to_table {
                         let mut row = Vec::with_capacity(arr.len());
                         for value in arr {
                             let val =
-                                json_to_table_f(value, config, mode_visitor, false).to_string();
+                                json_to_table_f(value, config, mode_visitor, depth + 1).to_string();
                             row.push(val);
                         }
 
@@ -242,34 +884,31 @@ mod json_to_table {
                 }
 
                 let mut table = builder.build();
-                set_table_style(&mut table, config);
+                set_table_style(&mut table, config, depth);
 
                 table
             }
-            Value::Object(map) => {
+            NodeKind::Object(map) => {
                 let mut builder = Builder::new();
 
-                let orientation = mode_visitor
-                    .as_mut()
-                    .map(|f| (f)(v))
-                    .unwrap_or(config.object_orientation);
+                let orientation = get_orientation(v, config.object_orientation, mode_visitor);
 
                 match orientation {
                     Orientation::Vertical => {
-                        for (key, value) in map {
+                        for (key, value) in ordered_entries(map, config) {
                             let val =
-                                json_to_table_f(value, config, mode_visitor, false).to_string();
-                            builder.add_record([key.clone(), val]);
+                                json_to_table_f(value, config, mode_visitor, depth + 1).to_string();
+                            builder.add_record([key_text(key, config), val]);
                         }
                     }
                     Orientation::Horizontal => {
                         let mut keys = Vec::with_capacity(map.len());
                         let mut vals = Vec::with_capacity(map.len());
-                        for (key, value) in map {
+                        for (key, value) in ordered_entries(map, config) {
                             let val =
-                                json_to_table_f(value, config, mode_visitor, false).to_string();
+                                json_to_table_f(value, config, mode_visitor, depth + 1).to_string();
                             vals.push(val);
-                            keys.push(key.clone());
+                            keys.push(key_text(key, config));
                         }
 
                         builder.hint_column_size(map.len());
@@ -279,18 +918,19 @@ mod json_to_table {
                 }
 
                 let mut table = builder.build();
-                set_table_style(&mut table, config);
+                set_table_style(&mut table, config, depth);
 
                 table
             }
-            value => {
-                let value = match value {
-                    Value::String(text) => Some(text.clone()),
-                    Value::Bool(val) => Some(val.to_string()),
-                    Value::Number(num) => Some(num.to_string()),
-                    Value::Null => None,
+            kind => {
+                let text = match kind {
+                    NodeKind::String(text) => Some(text.to_string()),
+                    NodeKind::Bool(val) => Some(val.to_string()),
+                    NodeKind::Number(num) => Some(num.to_string()),
+                    NodeKind::Null => None,
                     _ => unreachable!(),
                 };
+                let value = text.map(|text| value_text(kind, &text, config));
 
                 let mut builder = Builder::new();
 
@@ -300,9 +940,9 @@ mod json_to_table {
                 }
 
                 let mut table = builder.build();
-                set_table_style(&mut table, config);
+                set_table_style(&mut table, config, depth);
 
-                if !outer {
+                if depth > 0 {
                     table.with(Style::empty());
                 }
 
@@ -311,8 +951,106 @@ mod json_to_table {
         }
     }
 
-    fn json_to_table_r(
-        value: &Value,
+    fn json_to_table_columnar<V, F>(
+        objects: &[Vec<(Cow<'_, str>, Node<'_, V>)>],
+        more: usize,
+        config: &Config,
+        mode_visitor: &mut Option<&mut F>,
+        depth: usize,
+    ) -> Table
+    where
+        V: TableValue,
+        F: FnMut(&V) -> Orientation,
+    {
+        let keys = collect_keys(objects, config);
+
+        let mut builder = Builder::new();
+        builder.set_columns(keys.iter().map(|key| key_text(key, config)));
+
+        for map in objects {
+            let map = map
+                .iter()
+                .map(|(key, value)| (key.as_ref(), value))
+                .collect::<HashMap<_, _>>();
+
+            let mut row = Vec::with_capacity(keys.len());
+            for key in &keys {
+                let val = match map.get(key) {
+                    Some(value) => {
+                        json_to_table_f(value, config, mode_visitor, depth + 1).to_string()
+                    }
+                    None => config.missing_value.clone(),
+                };
+
+                row.push(val);
+            }
+
+            builder.add_record(row);
+        }
+
+        // the marker of cut off elements is a footer row, so it's not taken as a missing value
+        if let Some(marker) = Node::<V>::more_marker(more) {
+            let text = json_to_table_f(&marker, config, mode_visitor, depth + 1).to_string();
+            builder.add_record([text]);
+        }
+
+        let mut table = builder.build();
+        set_table_style(&mut table, config, depth);
+
+        if more > 0 {
+            let last_row = table.count_rows() - 1;
+            table.with(Modify::new(Cell(last_row, 0)).with(Span::column(keys.len())));
+        }
+
+        table
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn list_of_objects<'v, V>(list: &[Node<'v, V>]) -> Option<Vec<Vec<(Cow<'v, str>, Node<'v, V>)>>>
+    where
+        V: TableValue,
+    {
+        if list.is_empty() {
+            return None;
+        }
+
+        list.iter()
+            .map(|value| match value.kind() {
+                NodeKind::Object(map) => Some(map),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn collect_keys<'a, V>(
+        objects: &'a [Vec<(Cow<'_, str>, Node<'_, V>)>],
+        config: &Config,
+    ) -> Vec<&'a str> {
+        let mut keys: Vec<&str> = Vec::new();
+        let mut seen = HashSet::new();
+        for map in objects {
+            for (key, _) in map {
+                if seen.insert(key.as_ref()) {
+                    keys.push(key);
+                }
+            }
+        }
+
+        match config.keys_order {
+            KeysOrder::Insertion => {}
+            KeysOrder::Alphabetical => keys.sort_unstable(),
+            KeysOrder::Custom(compare) => keys.sort_by(|a, b| (compare)(a, b)),
+        }
+
+        if !config.pinned_keys.is_empty() {
+            keys.sort_by_key(|key| pinned_position(key, config));
+        }
+
+        keys
+    }
+
+    fn json_to_table_r<V: TableValue>(
+        value: &Node<'_, V>,
         config: &Config,
         row: usize,
         column: usize,
@@ -323,17 +1061,15 @@ mod json_to_table {
         used_splits: &[usize],
         width: Option<usize>,
     ) -> Table {
-        match value {
-            Value::String(..) | Value::Bool(..) | Value::Number(..) | Value::Null => {
-                let mut table = match value {
-                    Value::String(s) => col![s],
-                    Value::Bool(b) => col![b],
-                    Value::Number(n) => col![n],
-                    Value::Null => col![""],
-                    _ => unreachable!(),
-                };
+        let kind = value.kind();
+
+        match &kind {
+            NodeKind::String(..) | NodeKind::Bool(..) | NodeKind::Number(..) | NodeKind::Null => {
+                let text = value_to_string(&kind);
 
-                set_table_style(&mut table, config);
+                let mut table = col![value_text(&kind, &text, config)];
+
+                set_table_style(&mut table, config, 0);
 
                 table.with(Width::increase(width.unwrap_or(0)));
                 table.with(SetBottomChars(
@@ -342,17 +1078,19 @@ mod json_to_table {
                         .get_config()
                         .get_borders()
                         .top_intersection
+                        .as_ref()
+                        .and_then(BorderSymbol::as_char)
                         .unwrap_or(' '),
                 ));
 
                 table
             }
-            Value::Object(obj) => {
+            NodeKind::Object(obj) => {
                 if obj.is_empty() {
                     // a corner case where the object must behave as empty string
 
                     return json_to_table_r(
-                        &Value::String(String::new()),
+                        &Node::<V>::text(""),
                         config,
                         row,
                         column,
@@ -365,8 +1103,9 @@ mod json_to_table {
                     );
                 }
 
-                let map_length = obj.len();
-                let max_keys_width = obj
+                let entries = ordered_entries(obj, config);
+                let map_length = entries.len();
+                let max_keys_width = entries
                     .iter()
                     .map(|(key, _)| col![key].with(NoRightBorders).total_width())
                     .max()
@@ -376,7 +1115,7 @@ mod json_to_table {
                     Some(width) => width,
                     None => {
                         // build dummy table
-                        let map = obj.iter().enumerate().map(|(i, (key, value))| {
+                        let map = entries.iter().enumerate().map(|(i, (key, value))| {
                             let is_last = is_last && i + 1 == map_length;
 
                             let mut key = col![key];
@@ -410,7 +1149,7 @@ mod json_to_table {
                 };
 
                 let mut builder = Builder::new();
-                let mut iter = obj.iter().enumerate().peekable();
+                let mut iter = entries.iter().enumerate().peekable();
                 while let Some((i, value)) = iter.next() {
                     let row = row + i;
                     let (key, value) = value;
@@ -418,7 +1157,7 @@ mod json_to_table {
                     let mut was_intersection_touched = false;
                     let intersections = if i + 1 < map_length {
                         let (_, (_, value)) = iter.peek().unwrap();
-                        find_top_intersection(value)
+                        find_top_intersection(value, config)
                     } else {
                         let mut splits = used_splits.to_owned();
                         if !splits.is_empty() {
@@ -482,13 +1221,15 @@ mod json_to_table {
                         }
                     }
 
-                    let mut key = col![key];
-                    set_table_style(&mut key, config);
+                    let mut key = col![key_text(key, config)];
+                    set_table_style(&mut key, config, 0);
 
                     let top_intersection = key
                         .get_config()
                         .get_borders()
                         .top_intersection
+                        .as_ref()
+                        .and_then(BorderSymbol::as_char)
                         .unwrap_or(' ');
 
                     {
@@ -551,12 +1292,15 @@ mod json_to_table {
                 table.with(Style::empty()).with(Padding::zero());
                 table
             }
-            Value::Array(list) => {
+            NodeKind::Array(list, more) => {
+                let marker = Node::more_marker(*more);
+                let list = list.iter().chain(&marker).collect::<Vec<_>>();
+
                 if list.is_empty() {
                     // a corner case where the list must behave as empty string
 
                     return json_to_table_r(
-                        &Value::String(String::new()),
+                        &Node::<V>::text(""),
                         config,
                         row,
                         column,
@@ -603,8 +1347,7 @@ mod json_to_table {
                     let row = row + i;
 
                     let intersections = if i + 1 < map_length {
-                        let value = &list[i + 1];
-                        find_top_intersection(value)
+                        find_top_intersection(list[i + 1], config)
                     } else {
                         used_splits.to_owned()
                     };
@@ -672,17 +1415,21 @@ mod json_to_table {
         }
     }
 
-    fn find_top_intersection(table: &Value) -> Vec<usize> {
+    fn find_top_intersection<V: TableValue>(table: &Node<'_, V>, config: &Config) -> Vec<usize> {
         let mut intersections = Vec::new();
-        find_top_intersection_r(table, &mut intersections);
+        find_top_intersection_r(table, config, &mut intersections);
 
         intersections
     }
 
-    fn find_top_intersection_r(table: &Value, chars: &mut Vec<usize>) {
-        match table {
-            Value::String(_) | Value::Bool(_) | Value::Number(_) | Value::Null => (),
-            Value::Object(m) => {
+    fn find_top_intersection_r<V: TableValue>(
+        table: &Node<'_, V>,
+        config: &Config,
+        chars: &mut Vec<usize>,
+    ) {
+        match &table.kind() {
+            NodeKind::String(_) | NodeKind::Bool(_) | NodeKind::Number(_) | NodeKind::Null => (),
+            NodeKind::Object(m) => {
                 if m.is_empty() {
                     return;
                 }
@@ -695,25 +1442,96 @@ mod json_to_table {
 
                 chars.push(max_keys_width);
 
-                let (_, value) = m.iter().next().unwrap();
-                find_top_intersection_r(value, chars);
+                let (_, value) = ordered_entries(m, config)[0];
+                find_top_intersection_r(value, config, chars);
             }
-            Value::Array(list) => {
-                if let Some(value) = list.first() {
-                    find_top_intersection_r(value, chars);
+            NodeKind::Array(list, more) => match list.first() {
+                Some(value) => find_top_intersection_r(value, config, chars),
+                None => {
+                    if let Some(marker) = Node::<V>::more_marker(*more) {
+                        find_top_intersection_r(&marker, config, chars);
+                    }
                 }
-            }
+            },
         }
     }
 
-    fn set_table_style(table: &mut Table, config: &Config) {
+    fn set_table_style(table: &mut Table, config: &Config, depth: usize) {
         if let Some(cfg) = config.cfg.as_ref() {
             *table.get_config_mut() = cfg.clone();
         }
 
-        if let Some(style) = config.style.as_ref() {
+        if let Some(style) = config.depth_styles.get(&depth).or(config.style.as_ref()) {
             table.with(style);
         }
+
+        if let Some(&[left, right, top, bottom]) = config.depth_paddings.get(&depth) {
+            table.with(Padding::new(left, right, top, bottom));
+        }
+
+        #[cfg(feature = "color")]
+        if let Some(color) = config
+            .theme
+            .as_ref()
+            .and_then(|t| t.get_border_color(depth))
+        {
+            let color = tabled::papergrid::AnsiColor::from(color.clone());
+            table.get_config_mut().set_border_color_global(color);
+        }
+    }
+
+    fn ordered_entries<'a, 'v, V>(
+        map: &'a [(Cow<'v, str>, Node<'v, V>)],
+        config: &Config,
+    ) -> Vec<(&'a str, &'a Node<'v, V>)> {
+        let mut entries = map
+            .iter()
+            .map(|(key, value)| (key.as_ref(), value))
+            .collect::<Vec<_>>();
+
+        match config.keys_order {
+            KeysOrder::Insertion => {}
+            KeysOrder::Alphabetical => entries.sort_by_key(|(key, _)| *key),
+            KeysOrder::Custom(compare) => entries.sort_by(|(a, _), (b, _)| (compare)(a, b)),
+        }
+
+        if !config.pinned_keys.is_empty() {
+            entries.sort_by_key(|(key, _)| pinned_position(key, config));
+        }
+
+        entries
+    }
+
+    fn pinned_position(key: &str, config: &Config) -> usize {
+        config
+            .pinned_keys
+            .iter()
+            .position(|pinned| pinned == key)
+            .unwrap_or(usize::MAX)
+    }
+
+    fn key_text(key: &str, config: &Config) -> String {
+        #[cfg(feature = "color")]
+        if let Some(theme) = config.theme.as_ref() {
+            return theme.colorize_key(key);
+        }
+
+        #[cfg(not(feature = "color"))]
+        let _ = config;
+
+        key.to_owned()
+    }
+
+    fn value_text<V>(value: &NodeKind<'_, V>, text: &str, config: &Config) -> String {
+        #[cfg(feature = "color")]
+        if let Some(theme) = config.theme.as_ref() {
+            return theme.colorize_value(value, text);
+        }
+
+        #[cfg(not(feature = "color"))]
+        let _ = (value, config);
+
+        text.to_owned()
     }
 
     struct NoTopBorders;
@@ -730,20 +1548,6 @@ mod json_to_table {
         }
     }
 
-    struct NoBottomBorders;
-
-    impl<R> TableOption<R> for NoBottomBorders {
-        fn change(&mut self, table: &mut Table<R>) {
-            let mut borders = table.get_config().get_borders().clone();
-            borders.bottom = None;
-            borders.bottom_intersection = None;
-            borders.bottom_left = None;
-            borders.bottom_right = None;
-
-            table.get_config_mut().set_borders(borders);
-        }
-    }
-
     struct NoRightBorders;
 
     impl<R> TableOption<R> for NoRightBorders {
@@ -758,26 +1562,12 @@ mod json_to_table {
         }
     }
 
-    struct NoLeftBorders;
-
-    impl<R> TableOption<R> for NoLeftBorders {
-        fn change(&mut self, table: &mut Table<R>) {
-            let mut borders = table.get_config().get_borders().clone();
-            borders.top_left = None;
-            borders.bottom_left = None;
-            borders.vertical_left = None;
-            borders.horizontal_left = None;
-
-            table.get_config_mut().set_borders(borders);
-        }
-    }
-
     struct TopLeftChangeSplit;
 
     impl<R> TableOption<R> for TopLeftChangeSplit {
         fn change(&mut self, table: &mut Table<R>) {
             let mut borders = table.get_config().get_borders().clone();
-            borders.top_left = borders.top_intersection;
+            borders.top_left = borders.top_intersection.clone();
 
             table.get_config_mut().set_borders(borders);
         }
@@ -788,7 +1578,7 @@ mod json_to_table {
     impl<R> TableOption<R> for BottomLeftChangeSplit {
         fn change(&mut self, table: &mut Table<R>) {
             let mut borders = table.get_config().get_borders().clone();
-            borders.bottom_left = borders.horizontal_left;
+            borders.bottom_left = borders.horizontal_left.clone();
 
             table.get_config_mut().set_borders(borders);
         }
@@ -799,7 +1589,7 @@ mod json_to_table {
     impl<R> TableOption<R> for BottomLeftChangeSplitToIntersection {
         fn change(&mut self, table: &mut Table<R>) {
             let mut borders = table.get_config().get_borders().clone();
-            borders.bottom_left = borders.intersection;
+            borders.bottom_left = borders.intersection.clone();
 
             table.get_config_mut().set_borders(borders);
         }
@@ -810,7 +1600,7 @@ mod json_to_table {
     impl<R> TableOption<R> for BottomRightChangeToRight {
         fn change(&mut self, table: &mut Table<R>) {
             let mut borders = table.get_config().get_borders().clone();
-            borders.bottom_right = borders.horizontal_right;
+            borders.bottom_right = borders.horizontal_right.clone();
 
             table.get_config_mut().set_borders(borders);
         }
@@ -821,7 +1611,7 @@ mod json_to_table {
     impl<R> TableOption<R> for BottomLeftChangeToBottomIntersection {
         fn change(&mut self, table: &mut Table<R>) {
             let mut borders = table.get_config().get_borders().clone();
-            borders.bottom_left = borders.bottom_intersection;
+            borders.bottom_left = borders.bottom_intersection.clone();
 
             table.get_config_mut().set_borders(borders);
         }
@@ -0,0 +1,153 @@
+//! The module contains a [`Theme`] which is used to color a json by its types.
+
+use tabled::{color::Color, papergrid::AnsiColor};
+
+use crate::value::NodeKind;
+
+/// Theme is a set of colors used to highlight json values by their types,
+/// and to color borders of nested tables by their depth.
+///
+/// # Example
+///
+/// ```
+/// use json_to_table::{json_to_table, Theme};
+/// use serde_json::json;
+/// use tabled::color::Color;
+///
+/// let value = json!({"name": "John Doe", "age": 43});
+///
+/// let mut theme = Theme::new();
+/// theme.set_key(Color::FG_BLUE).set_number(Color::FG_RED);
+///
+/// let table = json_to_table(&value).set_theme(theme).to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+------+------------+\n",
+///         "| \u{1b}[34mage\u{1b}[39m  |  \u{1b}[31m43\u{1b}[39m        |\n",
+///         "+------+------------+\n",
+///         "| \u{1b}[34mname\u{1b}[39m |  John Doe  |\n",
+///         "+------+------------+",
+///     ),
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+    key: Option<Color>,
+    string: Option<Color>,
+    number: Option<Color>,
+    boolean: Option<Color>,
+    null: Option<Color>,
+    borders: Vec<Color>,
+}
+
+impl Theme {
+    /// Creates a theme without any colors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a theme which resembles a `jq` output.
+    pub fn jq() -> Self {
+        Self {
+            key: Some(Color::FG_BLUE),
+            string: Some(Color::FG_GREEN),
+            null: Some(Color::FG_BRIGHT_BLACK),
+            ..Default::default()
+        }
+    }
+
+    /// Set a color of object keys.
+    pub fn set_key(&mut self, color: Color) -> &mut Self {
+        self.key = Some(color);
+        self
+    }
+
+    /// Set a color of strings.
+    pub fn set_string(&mut self, color: Color) -> &mut Self {
+        self.string = Some(color);
+        self
+    }
+
+    /// Set a color of numbers.
+    pub fn set_number(&mut self, color: Color) -> &mut Self {
+        self.number = Some(color);
+        self
+    }
+
+    /// Set a color of booleans.
+    pub fn set_boolean(&mut self, color: Color) -> &mut Self {
+        self.boolean = Some(color);
+        self
+    }
+
+    /// Set a color of nulls.
+    ///
+    /// Notice that a null is rendered as an empty string,
+    /// so the color is used only for a type name in [`JsonTable::show_types`].
+    ///
+    /// [`JsonTable::show_types`]: crate::JsonTable::show_types
+    pub fn set_null(&mut self, color: Color) -> &mut Self {
+        self.null = Some(color);
+        self
+    }
+
+    /// Set colors of borders for each level of nested tables.
+    ///
+    /// The first color is used for the outer table, the second one for tables inside it and so on.
+    /// The colors are repeated if there are more levels than colors.
+    ///
+    /// In collapsed mode only the first color is used.
+    pub fn set_borders(&mut self, colors: impl IntoIterator<Item = Color>) -> &mut Self {
+        self.borders = colors.into_iter().collect();
+        self
+    }
+
+    pub(crate) fn colorize_key(&self, key: &str) -> String {
+        colorize(key, self.key.as_ref())
+    }
+
+    pub(crate) fn colorize_value<V>(&self, value: &NodeKind<'_, V>, text: &str) -> String {
+        let color = match value {
+            NodeKind::String(_) => self.string.as_ref(),
+            NodeKind::Number(_) => self.number.as_ref(),
+            NodeKind::Bool(_) => self.boolean.as_ref(),
+            NodeKind::Null => self.null.as_ref(),
+            NodeKind::Array(..) | NodeKind::Object(_) => None,
+        };
+
+        colorize(text, color)
+    }
+
+    pub(crate) fn get_border_color(&self, depth: usize) -> Option<&Color> {
+        if self.borders.is_empty() {
+            return None;
+        }
+
+        self.borders.get(depth % self.borders.len())
+    }
+}
+
+pub(crate) fn colorize(text: &str, color: Option<&Color>) -> String {
+    let color = match color {
+        Some(color) if !text.is_empty() => AnsiColor::from(color.clone()),
+        _ => return text.to_owned(),
+    };
+
+    // each line is colored separately so the color is not spread over table borders
+    let mut buf = String::with_capacity(text.len());
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        if !line.is_empty() {
+            buf.push_str(color.get_prefix());
+            buf.push_str(line);
+            buf.push_str(color.get_suffix());
+        }
+    }
+
+    buf
+}
@@ -0,0 +1,348 @@
+//! The module contains a [`TableValue`] trait which describes a value which can be rendered as a table,
+//! and its implementations for [`serde_json::Value`], `toml::Value` and `serde_yaml::Value`.
+
+use std::borrow::Cow;
+
+use crate::table::{summarize_array, summarize_object};
+
+/// A value which can be rendered as a table.
+///
+/// It's a map, a sequence or a scalar.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use json_to_table::{JsonTable, TableValue, ValueKind};
+///
+/// enum Config {
+///     Port(u16),
+///     Section(Vec<(String, Config)>),
+/// }
+///
+/// impl TableValue for Config {
+///     fn kind(&self) -> ValueKind<'_, Self> {
+///         match self {
+///             Config::Port(port) => ValueKind::Number(Cow::Owned(port.to_string())),
+///             Config::Section(values) => ValueKind::Object(
+///                 values
+///                     .iter()
+///                     .map(|(key, value)| (Cow::Borrowed(key.as_str()), value))
+///                     .collect(),
+///             ),
+///         }
+///     }
+/// }
+///
+/// let config = Config::Section(vec![(String::from("port"), Config::Port(8080))]);
+///
+/// let table = JsonTable::new(&config).to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+------+--------+\n",
+///         "| port |  8080  |\n",
+///         "+------+--------+",
+///     ),
+/// );
+/// ```
+pub trait TableValue {
+    /// Returns a kind of the value together with its content.
+    fn kind(&self) -> ValueKind<'_, Self>;
+}
+
+/// A kind of a [`TableValue`].
+#[derive(Debug)]
+pub enum ValueKind<'a, V: ?Sized> {
+    /// An absent value.
+    Null,
+    /// A boolean.
+    Bool(bool),
+    /// A number in its textual form.
+    Number(Cow<'a, str>),
+    /// A string.
+    String(Cow<'a, str>),
+    /// A sequence of values.
+    Array(Vec<&'a V>),
+    /// A map of values, in an order in which it must be rendered by default.
+    Object(Vec<(Cow<'a, str>, &'a V)>),
+}
+
+impl TableValue for serde_json::Value {
+    fn kind(&self) -> ValueKind<'_, Self> {
+        use serde_json::Value;
+
+        match self {
+            Value::Null => ValueKind::Null,
+            Value::Bool(b) => ValueKind::Bool(*b),
+            Value::Number(n) => ValueKind::Number(Cow::Owned(n.to_string())),
+            Value::String(s) => ValueKind::String(Cow::Borrowed(s)),
+            Value::Array(list) => ValueKind::Array(list.iter().collect()),
+            Value::Object(map) => ValueKind::Object(
+                map.iter()
+                    .map(|(key, value)| (Cow::Borrowed(key.as_str()), value))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl TableValue for toml::Value {
+    fn kind(&self) -> ValueKind<'_, Self> {
+        use toml::Value;
+
+        match self {
+            Value::Boolean(b) => ValueKind::Bool(*b),
+            Value::Integer(n) => ValueKind::Number(Cow::Owned(n.to_string())),
+            Value::Float(n) => ValueKind::Number(Cow::Owned(n.to_string())),
+            Value::String(s) => ValueKind::String(Cow::Borrowed(s)),
+            Value::Datetime(date) => ValueKind::String(Cow::Owned(date.to_string())),
+            Value::Array(list) => ValueKind::Array(list.iter().collect()),
+            Value::Table(map) => ValueKind::Object(
+                map.iter()
+                    .map(|(key, value)| (Cow::Borrowed(key.as_str()), value))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+impl TableValue for serde_yaml::Value {
+    fn kind(&self) -> ValueKind<'_, Self> {
+        use serde_yaml::Value;
+
+        match self {
+            Value::Null => ValueKind::Null,
+            Value::Bool(b) => ValueKind::Bool(*b),
+            Value::Number(n) => ValueKind::Number(Cow::Owned(n.to_string())),
+            Value::String(s) => ValueKind::String(Cow::Borrowed(s)),
+            Value::Sequence(list) => ValueKind::Array(list.iter().collect()),
+            Value::Mapping(map) => ValueKind::Object(
+                map.iter()
+                    .map(|(key, value)| (yaml_key(key), value))
+                    .collect(),
+            ),
+            Value::Tagged(tagged) => tagged.value.kind(),
+        }
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+fn yaml_key(key: &serde_yaml::Value) -> Cow<'_, str> {
+    use serde_yaml::Value;
+
+    match key {
+        Value::String(s) => Cow::Borrowed(s),
+        Value::Null => Cow::Borrowed("null"),
+        Value::Bool(b) => Cow::Owned(b.to_string()),
+        Value::Number(n) => Cow::Owned(n.to_string()),
+        key => match serde_yaml::to_string(key) {
+            Ok(text) => Cow::Owned(text.trim_end().to_owned()),
+            Err(_) => Cow::Borrowed(""),
+        },
+    }
+}
+
+/// A value prepared for rendering.
+///
+/// It's walked lazily, so a content of an original value is read only when it's rendered,
+/// and limits are applied at the same time.
+/// It keeps a reference to an original value, if there's one,
+/// so it could be given to a mode visitor.
+#[derive(Debug)]
+pub(crate) struct Node<'a, V> {
+    source: Source<'a, V>,
+    limits: Limits,
+    depth: usize,
+}
+
+#[derive(Debug)]
+enum Source<'a, V> {
+    Value(&'a V),
+    Synthetic(NodeKind<'a, V>),
+}
+
+#[derive(Debug)]
+pub(crate) enum NodeKind<'a, V> {
+    Null,
+    Bool(bool),
+    Number(Cow<'a, str>),
+    String(Cow<'a, str>),
+    // a list and a number of elements which were cut off from its end
+    Array(Vec<Node<'a, V>>, usize),
+    Object(Vec<(Cow<'a, str>, Node<'a, V>)>),
+}
+
+/// Limits of a rendered value, see [`JsonTable::max_depth`].
+///
+/// [`JsonTable::max_depth`]: crate::JsonTable::max_depth
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Limits {
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) max_array_length: Option<usize>,
+    pub(crate) max_string_length: Option<usize>,
+}
+
+impl<'a, V> Node<'a, V> {
+    pub(crate) fn new(value: &'a V) -> Self {
+        Self::from_source(Source::Value(value))
+    }
+
+    pub(crate) fn synthetic(kind: NodeKind<'a, V>) -> Self {
+        Self::from_source(Source::Synthetic(kind))
+    }
+
+    pub(crate) fn text(text: impl Into<Cow<'a, str>>) -> Self {
+        Self::synthetic(NodeKind::String(text.into()))
+    }
+
+    /// Returns a marker of elements which were cut off from a list, like `…2 more`.
+    pub(crate) fn more_marker(more: usize) -> Option<Self> {
+        if more == 0 {
+            return None;
+        }
+
+        Some(Self::text(format!("…{} more", more)))
+    }
+
+    fn from_source(source: Source<'a, V>) -> Self {
+        Self {
+            source,
+            limits: Limits::default(),
+            depth: 0,
+        }
+    }
+
+    /// Returns a node which is a root of a limited value.
+    pub(crate) fn limited(self, limits: Limits) -> Self {
+        Self {
+            source: self.source,
+            limits,
+            depth: 0,
+        }
+    }
+
+    /// Returns an original value.
+    pub(crate) fn origin(&self) -> Option<&'a V> {
+        match self.source {
+            Source::Value(value) => Some(value),
+            Source::Synthetic(_) => None,
+        }
+    }
+
+    fn child(&self, node: Self) -> Self {
+        Self {
+            source: node.source,
+            limits: self.limits,
+            depth: self.depth + 1,
+        }
+    }
+}
+
+impl<'a, V> Node<'a, V>
+where
+    V: TableValue,
+{
+    /// Reads a kind of the value, with its children which are read on demand.
+    pub(crate) fn kind(&self) -> NodeKind<'a, V> {
+        let kind = match &self.source {
+            Source::Value(value) => match value.kind() {
+                ValueKind::Null => NodeKind::Null,
+                ValueKind::Bool(b) => NodeKind::Bool(b),
+                ValueKind::Number(n) => NodeKind::Number(n),
+                ValueKind::String(s) => NodeKind::String(s),
+                ValueKind::Array(list) => NodeKind::Array(
+                    list.into_iter()
+                        .map(|value| self.child(Node::new(value)))
+                        .collect(),
+                    0,
+                ),
+                ValueKind::Object(map) => NodeKind::Object(
+                    map.into_iter()
+                        .map(|(key, value)| (key, self.child(Node::new(value))))
+                        .collect(),
+                ),
+            },
+            Source::Synthetic(NodeKind::Array(list, more)) => NodeKind::Array(
+                list.iter().map(|value| self.child(value.clone())).collect(),
+                *more,
+            ),
+            Source::Synthetic(NodeKind::Object(map)) => NodeKind::Object(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), self.child(value.clone())))
+                    .collect(),
+            ),
+            Source::Synthetic(kind) => kind.clone(),
+        };
+
+        self.limits.apply(kind, self.depth)
+    }
+}
+
+impl Limits {
+    fn apply<'a, V>(&self, kind: NodeKind<'a, V>, depth: usize) -> NodeKind<'a, V> {
+        let is_too_deep = matches!(self.max_depth, Some(max) if depth >= max);
+
+        match kind {
+            NodeKind::Object(map) if is_too_deep => {
+                NodeKind::String(summarize_object(map.len()).into())
+            }
+            NodeKind::Array(list, more) if is_too_deep => {
+                NodeKind::String(summarize_array(list.len() + more).into())
+            }
+            NodeKind::Array(mut list, more) => match self.max_array_length {
+                Some(max) if list.len() > max => {
+                    let cut = list.len() - max;
+                    list.truncate(max);
+                    NodeKind::Array(list, more + cut)
+                }
+                _ => NodeKind::Array(list, more),
+            },
+            NodeKind::String(text) => match self.max_string_length {
+                Some(max) if text.chars().count() > max => {
+                    let mut text = text.chars().take(max).collect::<String>();
+                    text.push('…');
+                    NodeKind::String(text.into())
+                }
+                _ => NodeKind::String(text),
+            },
+            kind => kind,
+        }
+    }
+}
+
+impl<V> Clone for Node<'_, V> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            limits: self.limits,
+            depth: self.depth,
+        }
+    }
+}
+
+impl<V> Clone for Source<'_, V> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Value(value) => Self::Value(value),
+            Self::Synthetic(kind) => Self::Synthetic(kind.clone()),
+        }
+    }
+}
+
+impl<V> Clone for NodeKind<'_, V> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Null => Self::Null,
+            Self::Bool(b) => Self::Bool(*b),
+            Self::Number(n) => Self::Number(n.clone()),
+            Self::String(s) => Self::String(s.clone()),
+            Self::Array(list, more) => Self::Array(list.clone(), *more),
+            Self::Object(map) => Self::Object(map.clone()),
+        }
+    }
+}
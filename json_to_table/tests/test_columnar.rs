@@ -0,0 +1,118 @@
+use json_to_table::{json_to_table, KeysOrder};
+use serde_json::json;
+use tabled::Style;
+
+#[test]
+fn columnar_test() {
+    let value = json!([
+        {"name": "Aleix Melon", "id": "E00245", "age": 23},
+        {"name": "Bob Washington", "id": "E01245", "age": 34},
+    ]);
+
+    let table = json_to_table(&value)
+        .columnar()
+        .set_style(Style::modern())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "┌──────┬──────────┬──────────────────┐\n",
+            "│ age  │ id       │ name             │\n",
+            "├──────┼──────────┼──────────────────┤\n",
+            "│  23  │  E00245  │  Aleix Melon     │\n",
+            "├──────┼──────────┼──────────────────┤\n",
+            "│  34  │  E01245  │  Bob Washington  │\n",
+            "└──────┴──────────┴──────────────────┘",
+        ),
+    );
+}
+
+#[test]
+fn columnar_missing_keys_test() {
+    let value = json!([
+        {"b": 1},
+        {"a": 2},
+        {"c": 3, "b": 4},
+    ]);
+
+    let table = json_to_table(&value).columnar().to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----+-----+-----+\n",
+            "| b   | a   | c   |\n",
+            "+-----+-----+-----+\n",
+            "|  1  |     |     |\n",
+            "+-----+-----+-----+\n",
+            "|     |  2  |     |\n",
+            "+-----+-----+-----+\n",
+            "|  4  |     |  3  |\n",
+            "+-----+-----+-----+",
+        ),
+    );
+
+    let table = json_to_table(&value)
+        .columnar()
+        .set_keys_order(KeysOrder::Alphabetical)
+        .set_missing_value("-")
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----+-----+-----+\n",
+            "| a   | b   | c   |\n",
+            "+-----+-----+-----+\n",
+            "| -   |  1  | -   |\n",
+            "+-----+-----+-----+\n",
+            "|  2  | -   | -   |\n",
+            "+-----+-----+-----+\n",
+            "| -   |  4  |  3  |\n",
+            "+-----+-----+-----+",
+        ),
+    );
+}
+
+#[test]
+fn columnar_nested_test() {
+    let value = json!({
+        "users": [
+            {"name": "Aleix", "roles": ["Dev", "DBA"]},
+            {"name": "Bob", "address": {"city": "London"}},
+        ],
+    });
+
+    let table = json_to_table(&value).columnar().to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-------+-----------------------------------------------+\n",
+            "| users | +---------+-----------+---------------------+ |\n",
+            "|       | | name    | roles     | address             | |\n",
+            "|       | +---------+-----------+---------------------+ |\n",
+            "|       | |  Aleix  | +-------+ |                     | |\n",
+            "|       | |         | |  Dev  | |                     | |\n",
+            "|       | |         | +-------+ |                     | |\n",
+            "|       | |         | |  DBA  | |                     | |\n",
+            "|       | |         | +-------+ |                     | |\n",
+            "|       | +---------+-----------+---------------------+ |\n",
+            "|       | |  Bob    |           | +------+----------+ | |\n",
+            "|       | |         |           | | city |  London  | | |\n",
+            "|       | |         |           | +------+----------+ | |\n",
+            "|       | +---------+-----------+---------------------+ |\n",
+            "+-------+-----------------------------------------------+",
+        ),
+    );
+}
+
+#[test]
+fn columnar_ignores_mixed_list_test() {
+    let value = json!([{"a": 1}, 2]);
+
+    let table = json_to_table(&value).columnar().to_string();
+
+    assert_eq!(table, json_to_table(&value).to_string());
+}
@@ -0,0 +1,153 @@
+use json_to_table::json_diff_table;
+use serde_json::json;
+use tabled::Style;
+
+#[test]
+fn diff_test() {
+    let old = json!({
+        "id": 1,
+        "address": {"city": "London", "zip": "SW1A"},
+        "tags": ["a", "b"],
+        "meta": {"x": 1},
+        "kind": {"a": 1},
+        "gone": null,
+    });
+    let new = json!({
+        "id": 1,
+        "address": {"city": "London", "zip": "SW1A"},
+        "tags": ["a"],
+        "meta": {"x": 2, "y": [1]},
+        "kind": "simple",
+        "zip code": true,
+    });
+
+    let table = json_diff_table(&old, &new)
+        .set_style(Style::blank())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "     path           old         new       \n",
+            "     address        {…2 keys}   {…2 keys} \n",
+            " -   gone           null                  \n",
+            "     id             1           1         \n",
+            " ~   kind           {\"a\":1}     \"simple\"  \n",
+            " ~   meta.x         1           2         \n",
+            " +   meta.y                     [1]       \n",
+            "     tags[0]        \"a\"         \"a\"       \n",
+            " -   tags[1]        \"b\"                   \n",
+            " +   [\"zip code\"]               true      ",
+        )
+    );
+}
+
+#[test]
+fn diff_hide_unchanged_test() {
+    let old = json!({"id": 1, "meta": {"x": 1}, "tags": ["a", "b"]});
+    let new = json!({"id": 1, "meta": {"x": 2}, "tags": ["a"]});
+
+    let table = json_diff_table(&old, &new).hide_unchanged().to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+---+---------+-----+-----+\n",
+            "|   | path    | old | new |\n",
+            "+---+---------+-----+-----+\n",
+            "| ~ | meta.x  | 1   | 2   |\n",
+            "+---+---------+-----+-----+\n",
+            "| - | tags[1] | \"b\" |     |\n",
+            "+---+---------+-----+-----+",
+        )
+    );
+}
+
+#[test]
+fn diff_root_test() {
+    let table = json_diff_table(&json!(1), &json!("1")).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+---+------+-----+-----+\n",
+            "|   | path | old | new |\n",
+            "+---+------+-----+-----+\n",
+            "| ~ | $    | 1   | \"1\" |\n",
+            "+---+------+-----+-----+",
+        )
+    );
+
+    let table = json_diff_table(&json!([]), &json!([]))
+        .hide_unchanged()
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+--+------+-----+-----+\n",
+            "|  | path | old | new |\n",
+            "+--+------+-----+-----+",
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn diff_colored_test() {
+    let old = json!({"a": 1, "b": 2});
+    let new = json!({"a": 1, "c": 3});
+
+    let table = json_diff_table(&old, &new).colored().to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+---+------+-----+-----+\n",
+            "|   | path | old | new |\n",
+            "+---+------+-----+-----+\n",
+            "|   | a    | 1   | 1   |\n",
+            "+---+------+-----+-----+\n",
+            "| \u{1b}[31m-\u{1b}[39m | b    | \u{1b}[31m2\u{1b}[39m   |     |\n",
+            "+---+------+-----+-----+\n",
+            "| \u{1b}[32m+\u{1b}[39m | c    |     | \u{1b}[32m3\u{1b}[39m   |\n",
+            "+---+------+-----+-----+",
+        )
+    );
+}
+
+#[test]
+fn diff_added_keys_order_test() {
+    let old = json!({"b": 1, "d": 2});
+    let new = json!({"a": 0, "b": 1, "c": 3, "d": 2});
+
+    let table = json_diff_table(&old, &new)
+        .set_style(Style::blank())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "     path   old   new \n",
+            " +   a            0   \n",
+            "     b      1     1   \n",
+            " +   c            3   \n",
+            "     d      2     2   ",
+        )
+    );
+}
+
+#[test]
+fn diff_root_unchanged_test() {
+    let table = json_diff_table(&json!([1]), &json!([1]))
+        .set_style(Style::blank())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "    path   old         new       \n",
+            "    $      […1 item]   […1 item] ",
+        )
+    );
+}
@@ -0,0 +1,92 @@
+use json_to_table::{json_to_table, Orientation};
+use serde_json::json;
+use tabled::Style;
+
+#[test]
+fn flatten_test() {
+    let value = json!({
+        "name": "John Doe",
+        "age": 43,
+        "married": true,
+        "spouse": null,
+        "address": {"city": "London", "zip code": "SW1A 2AA"},
+        "phones": ["+44 1234567", ["+44 2345678"]],
+        "children": [],
+    });
+
+    let table = json_to_table(&value)
+        .flatten()
+        .set_style(Style::blank())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " address.city          London      \n",
+            " address[\"zip code\"]   SW1A 2AA    \n",
+            " age                   43          \n",
+            " children              []          \n",
+            " married               true        \n",
+            " name                  John Doe    \n",
+            " phones[0]             +44 1234567 \n",
+            " phones[1][0]          +44 2345678 \n",
+            " spouse                            ",
+        ),
+    );
+}
+
+#[test]
+fn flatten_with_types_test() {
+    let value = json!([{"id": 1, "tags": ["a"]}, null]);
+
+    let table = json_to_table(&value)
+        .flatten()
+        .show_types()
+        .set_style(Style::blank())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " [0].id        1   number \n",
+            " [0].tags[0]   a   string \n",
+            " [1]               null   ",
+        ),
+    );
+}
+
+#[test]
+fn flatten_horizontal_test() {
+    let value = json!({"a": {"b": 1}, "c": [true]});
+
+    let table = json_to_table(&value)
+        .flatten()
+        .show_types()
+        .set_object_mode(Orientation::Horizontal)
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+--------+------+\n",
+            "| a.b    | c[0] |\n",
+            "+--------+------+\n",
+            "| 1      | true |\n",
+            "+--------+------+\n",
+            "| number | bool |\n",
+            "+--------+------+",
+        ),
+    );
+}
+
+#[test]
+fn flatten_scalar_test() {
+    let value = json!("Hello World");
+
+    let table = json_to_table(&value).flatten().to_string();
+
+    assert_eq!(
+        table,
+        "+--+-------------+\n|  | Hello World |\n+--+-------------+"
+    );
+}
@@ -0,0 +1,196 @@
+use json_to_table::{json_to_table, KeysOrder};
+use serde_json::json;
+use tabled::Style;
+
+#[test]
+fn keys_order_pinned_test() {
+    let value = json!({"b": {"z": 1, "id": 2}, "a": [{"x": 1, "id": 2}], "id": 0});
+
+    let table = json_to_table(&value)
+        .set_keys_order(KeysOrder::Alphabetical)
+        .set_pinned_keys(["id"])
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+----+------------------+\n",
+            "| id |  0               |\n",
+            "+----+------------------+\n",
+            "| a  | +--------------+ |\n",
+            "|    | | +----+-----+ | |\n",
+            "|    | | | id |  2  | | |\n",
+            "|    | | +----+-----+ | |\n",
+            "|    | | | x  |  1  | | |\n",
+            "|    | | +----+-----+ | |\n",
+            "|    | +--------------+ |\n",
+            "+----+------------------+\n",
+            "| b  | +----+-----+     |\n",
+            "|    | | id |  2  |     |\n",
+            "|    | +----+-----+     |\n",
+            "|    | | z  |  1  |     |\n",
+            "|    | +----+-----+     |\n",
+            "+----+------------------+",
+        )
+    );
+}
+
+#[test]
+fn keys_order_custom_test() {
+    let value = json!({"b": {"z": 1, "id": 2}, "a": [{"x": 1, "id": 2}], "id": 0});
+
+    let table = json_to_table(&value)
+        .set_keys_order(KeysOrder::Custom(|a, b| b.cmp(a)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+----+------------------+\n",
+            "| id |  0               |\n",
+            "+----+------------------+\n",
+            "| b  | +----+-----+     |\n",
+            "|    | | z  |  1  |     |\n",
+            "|    | +----+-----+     |\n",
+            "|    | | id |  2  |     |\n",
+            "|    | +----+-----+     |\n",
+            "+----+------------------+\n",
+            "| a  | +--------------+ |\n",
+            "|    | | +----+-----+ | |\n",
+            "|    | | | x  |  1  | | |\n",
+            "|    | | +----+-----+ | |\n",
+            "|    | | | id |  2  | | |\n",
+            "|    | | +----+-----+ | |\n",
+            "|    | +--------------+ |\n",
+            "+----+------------------+",
+        )
+    );
+}
+
+#[test]
+fn keys_order_pinned_collapsed_test() {
+    let value = json!({"b": {"z": 1, "id": 2}, "a": [{"x": 1, "id": 2}], "id": 0});
+
+    let table = json_to_table(&value)
+        .set_pinned_keys(["id"])
+        .collapse()
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+----+--------+\n",
+            "| id | 0      |\n",
+            "+----+----+---+\n",
+            "| a  | id | 2 |\n",
+            "|    +----+---+\n",
+            "|    | x  | 1 |\n",
+            "+----+----+---+\n",
+            "| b  | id | 2 |\n",
+            "|    +----+---+\n",
+            "|    | z  | 1 |\n",
+            "+----+----+---+",
+        )
+    );
+}
+
+#[test]
+fn keys_order_pinned_flatten_test() {
+    let value = json!({"b": {"z": 1, "id": 2}, "a": [{"x": 1, "id": 2}], "id": 0});
+
+    let table = json_to_table(&value)
+        .set_pinned_keys(["id"])
+        .flatten()
+        .set_style(Style::blank())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " id        0 \n",
+            " a[0].id   2 \n",
+            " a[0].x    1 \n",
+            " b.id      2 \n",
+            " b.z       1 ",
+        )
+    );
+}
+
+#[test]
+fn keys_order_pinned_columnar_test() {
+    let value = json!([{"x": 1, "id": 2}]);
+
+    let table = json_to_table(&value)
+        .set_pinned_keys(["id"])
+        .columnar()
+        .set_keys_order(KeysOrder::Alphabetical)
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----+-----+\n",
+            "| id  | x   |\n",
+            "+-----+-----+\n",
+            "|  2  |  1  |\n",
+            "+-----+-----+",
+        )
+    );
+}
+
+#[test]
+fn depth_style_test() {
+    let value = json!({"b": {"z": 1, "id": 2}, "a": [{"x": 1, "id": 2}], "id": 0});
+
+    let table = json_to_table(&value)
+        .set_style(Style::modern())
+        .set_depth_style(1, Style::ascii())
+        .set_depth_padding(0, 2, 2, 0, 0)
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "┌──────┬────────────────────┐\n",
+            "│  a   │  +--------------+  │\n",
+            "│      │  | ┌────┬─────┐ |  │\n",
+            "│      │  | │ id │  2  │ |  │\n",
+            "│      │  | ├────┼─────┤ |  │\n",
+            "│      │  | │ x  │  1  │ |  │\n",
+            "│      │  | └────┴─────┘ |  │\n",
+            "│      │  +--------------+  │\n",
+            "├──────┼────────────────────┤\n",
+            "│  b   │  +----+-----+      │\n",
+            "│      │  | id |  2  |      │\n",
+            "│      │  +----+-----+      │\n",
+            "│      │  | z  |  1  |      │\n",
+            "│      │  +----+-----+      │\n",
+            "├──────┼────────────────────┤\n",
+            "│  id  │   0                │\n",
+            "└──────┴────────────────────┘",
+        )
+    );
+}
+
+#[test]
+fn keys_order_custom_columnar_test() {
+    let value = json!([{"a": 1, "ccc": 2}, {"bb": 3}]);
+
+    let table = json_to_table(&value)
+        .set_keys_order(KeysOrder::Custom(|a, b| b.len().cmp(&a.len())))
+        .columnar()
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----+-----+-----+\n",
+            "| ccc | bb  | a   |\n",
+            "+-----+-----+-----+\n",
+            "|  2  |     |  1  |\n",
+            "+-----+-----+-----+\n",
+            "|     |  3  |     |\n",
+            "+-----+-----+-----+",
+        )
+    );
+}
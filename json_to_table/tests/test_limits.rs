@@ -0,0 +1,120 @@
+use json_to_table::json_to_table;
+use serde_json::json;
+
+#[test]
+fn max_depth_test() {
+    let value = json!({"deep": {"a": {"b": [1]}}, "list": [1, 2]});
+
+    let table = json_to_table(&value).max_depth(2).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+------+--------------------+\n",
+            "| deep | +---+------------+ |\n",
+            "|      | | a |  {…1 key}  | |\n",
+            "|      | +---+------------+ |\n",
+            "+------+--------------------+\n",
+            "| list | +-----+            |\n",
+            "|      | |  1  |            |\n",
+            "|      | +-----+            |\n",
+            "|      | |  2  |            |\n",
+            "|      | +-----+            |\n",
+            "+------+--------------------+",
+        ),
+    );
+}
+
+#[test]
+fn max_depth_zero_test() {
+    let value = json!([1, 2, 3]);
+
+    let table = json_to_table(&value).max_depth(0).to_string();
+
+    assert_eq!(table, "+------------+\n| […3 items] |\n+------------+");
+}
+
+#[test]
+fn max_depth_collapsed_test() {
+    let value = json!({"deep": {"a": {"b": [1]}}, "list": [[1, 2]]});
+
+    let table = json_to_table(&value).max_depth(2).collapse().to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+------+---+----------+\n",
+            "| deep | a | {…1 key} |\n",
+            "+------+---+----------+\n",
+            "| list | […2 items]   |\n",
+            "+------+--------------+",
+        ),
+    );
+}
+
+#[test]
+fn max_array_length_test() {
+    let value = json!([1, 2, 3, 4]);
+
+    let table = json_to_table(&value).max_array_length(2).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----------+\n",
+            "|  1        |\n",
+            "+-----------+\n",
+            "|  2        |\n",
+            "+-----------+\n",
+            "|  …2 more  |\n",
+            "+-----------+",
+        ),
+    );
+}
+
+#[test]
+fn max_string_length_test() {
+    let value = json!({"text": "Hello World", "short": "Hi"});
+
+    let table = json_to_table(&value).max_string_length(5).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-------+----------+\n",
+            "| short |  Hi      |\n",
+            "+-------+----------+\n",
+            "| text  |  Hello…  |\n",
+            "+-------+----------+",
+        ),
+    );
+}
+
+#[test]
+fn max_array_length_columnar_test() {
+    let value = json!([
+        {"id": 1, "name": "a"},
+        {"id": 2, "name": "b"},
+        {"id": 3, "name": "c"},
+    ]);
+
+    let table = json_to_table(&value)
+        .columnar()
+        .max_array_length(2)
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----+------+\n",
+            "| id  | name |\n",
+            "+-----+------+\n",
+            "|  1  |  a   |\n",
+            "+-----+------+\n",
+            "|  2  |  b   |\n",
+            "+-----+------+\n",
+            "|  …1 more   |\n",
+            "+-----+------+",
+        ),
+    );
+}
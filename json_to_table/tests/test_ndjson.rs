@@ -0,0 +1,173 @@
+use std::io;
+
+use json_to_table::NdjsonTable;
+use tabled::Style;
+
+const INPUT: &str = concat!(
+    "{\"id\": 1, \"name\": \"Bob\"}\n",
+    "\n",
+    "{\"id\": 2, \"email\": \"a@b.c\"}\n",
+    "{\"id\": 3, \"name\": null, \"tags\": [\"x\", \"y\"]}\n",
+    "{\"id\": 4, \"name\": \"Alexander the Great\"}\n",
+);
+
+#[test]
+fn ndjson_build_test() {
+    let table = NdjsonTable::new(INPUT.as_bytes()).build().unwrap();
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "+----+---------------------+-------+-----------+\n",
+            "| id | name                | email | tags      |\n",
+            "+----+---------------------+-------+-----------+\n",
+            "| 1  | Bob                 |       |           |\n",
+            "+----+---------------------+-------+-----------+\n",
+            "| 2  |                     | a@b.c |           |\n",
+            "+----+---------------------+-------+-----------+\n",
+            "| 3  |                     |       | [\"x\",\"y\"] |\n",
+            "+----+---------------------+-------+-----------+\n",
+            "| 4  | Alexander the Great |       |           |\n",
+            "+----+---------------------+-------+-----------+",
+        )
+    );
+}
+
+#[test]
+fn ndjson_max_rows_test() {
+    let mut table = NdjsonTable::new(INPUT.as_bytes());
+    table.set_max_rows(2).set_style(Style::modern());
+
+    assert_eq!(
+        table.build().unwrap().to_string(),
+        concat!(
+            "┌────┬──────┬───────┐\n",
+            "│ id │ name │ email │\n",
+            "├────┼──────┼───────┤\n",
+            "│ 1  │ Bob  │       │\n",
+            "├────┼──────┼───────┤\n",
+            "│ 2  │      │ a@b.c │\n",
+            "└────┴──────┴───────┘",
+        )
+    );
+}
+
+#[test]
+fn ndjson_stream_test() {
+    let mut table = NdjsonTable::new(INPUT.as_bytes());
+    table.set_sample_size(2);
+
+    let mut out = Vec::new();
+    table.stream(&mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            "+----+------+-------+\n",
+            "| id | name | email |\n",
+            "+----+------+-------+\n",
+            "| 1  | Bob  |       |\n",
+            "+----+------+-------+\n",
+            "| 2  |      | a@b.c |\n",
+            "+----+------+-------+\n",
+            "| 3  |      |       |\n",
+            "+----+------+-------+\n",
+            "| 4  | Ale… |       |\n",
+            "+----+------+-------+\n",
+        )
+    );
+}
+
+#[test]
+fn ndjson_stream_with_style_test() {
+    let mut table = NdjsonTable::new(INPUT.as_bytes());
+    table.set_sample_size(1).set_style(Style::modern());
+
+    let mut out = Vec::new();
+    table.stream(&mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            "┌────┬──────┐\n",
+            "│ id │ name │\n",
+            "├────┼──────┤\n",
+            "│ 1  │ Bob  │\n",
+            "├────┼──────┤\n",
+            "│ 2  │      │\n",
+            "├────┼──────┤\n",
+            "│ 3  │      │\n",
+            "├────┼──────┤\n",
+            "│ 4  │ Ale… │\n",
+            "└────┴──────┘\n",
+        )
+    );
+}
+
+#[test]
+fn ndjson_stream_within_sample_test() {
+    let mut out = Vec::new();
+    NdjsonTable::new(INPUT.as_bytes()).stream(&mut out).unwrap();
+
+    let table = NdjsonTable::new(INPUT.as_bytes()).build().unwrap();
+
+    assert_eq!(String::from_utf8(out).unwrap(), format!("{}\n", table));
+}
+
+#[test]
+fn ndjson_not_object_test() {
+    let input = "{\"id\": 1}\n\n[1, 2]\n";
+
+    let err = NdjsonTable::new(input.as_bytes()).build().unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "line 3 is not a json object");
+}
+
+#[test]
+fn ndjson_invalid_json_test() {
+    let input = "{\"id\": 1}\n{\"id\": \n";
+
+    let err = NdjsonTable::new(input.as_bytes()).build().unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn ndjson_empty_test() {
+    let table = NdjsonTable::new("".as_bytes()).build().unwrap();
+
+    assert_eq!(table.to_string(), "");
+}
+
+#[test]
+fn ndjson_stream_without_columns_test() {
+    let mut table = NdjsonTable::new("{}\n{}\n{}\n".as_bytes());
+    table.set_sample_size(1);
+
+    let mut out = Vec::new();
+    table.stream(&mut out).unwrap();
+
+    assert_eq!(String::from_utf8(out).unwrap(), "");
+}
+
+#[test]
+fn ndjson_stream_with_header_split_test() {
+    let mut table = NdjsonTable::new(INPUT.as_bytes());
+    table.set_sample_size(1).set_style(Style::psql());
+
+    let mut out = Vec::new();
+    table.stream(&mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            " id | name \n",
+            "----+------\n",
+            " 1  | Bob  \n",
+            " 2  |      \n",
+            " 3  |      \n",
+            " 4  | Ale… \n",
+        )
+    );
+}
@@ -0,0 +1,106 @@
+use json_to_table::json_to_table;
+use serde_json::json;
+
+#[test]
+fn select_pointer_test() {
+    let value = json!({"data": {"items": [{"name": "a"}, {"name": "b"}]}});
+
+    let table = json_to_table(&value).select("/data/items/1").to_string();
+
+    assert_eq!(
+        table,
+        concat!("+------+-----+\n", "| name |  b  |\n", "+------+-----+",),
+    );
+
+    let table = json_to_table(&value).select("").to_string();
+
+    assert_eq!(table, json_to_table(&value).to_string());
+}
+
+#[test]
+fn select_pointer_escaped_test() {
+    let value = json!({"a/b": {"c~d": 3}});
+
+    let table = json_to_table(&value)
+        .select("/a~1b/c~0d")
+        .show_paths()
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+------------+-----+\n",
+            "| /a~1b/c~0d |  3  |\n",
+            "+------------+-----+",
+        ),
+    );
+}
+
+#[test]
+fn select_path_wildcard_test() {
+    let value = json!({"items": [{"name": "a"}, {"id": 1}, {"name": "b"}]});
+
+    let table = json_to_table(&value).select("$.items[*].name").to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----+\n",
+            "|  a  |\n",
+            "+-----+\n",
+            "|  b  |\n",
+            "+-----+",
+        ),
+    );
+
+    let table = json_to_table(&value)
+        .select("$['items'][*].*")
+        .show_paths()
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----------------+-----+\n",
+            "| $.items[0].name |  a  |\n",
+            "+-----------------+-----+\n",
+            "| $.items[1].id   |  1  |\n",
+            "+-----------------+-----+\n",
+            "| $.items[2].name |  b  |\n",
+            "+-----------------+-----+",
+        ),
+    );
+}
+
+#[test]
+fn select_path_collapsed_test() {
+    let value = json!({"items": [{"name": "a", "tags": [1, 2]}]});
+
+    let table = json_to_table(&value)
+        .select("$.items[0]")
+        .collapse()
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+------+---+\n",
+            "| name | a |\n",
+            "+------+---+\n",
+            "| tags | 1 |\n",
+            "|      +---+\n",
+            "|      | 2 |\n",
+            "+------+---+",
+        ),
+    );
+}
+
+#[test]
+fn select_nothing_test() {
+    let value = json!({"items": [1, 2]});
+
+    assert_eq!(json_to_table(&value).select("/items/2").to_string(), "");
+    assert_eq!(json_to_table(&value).select("$.nothing").to_string(), "");
+    assert_eq!(json_to_table(&value).select("items").to_string(), "");
+    assert_eq!(json_to_table(&value).select("$[1").to_string(), "");
+}
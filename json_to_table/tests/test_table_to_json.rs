@@ -0,0 +1,146 @@
+use json_to_table::{table_to_json, JsonOptions};
+use serde_json::json;
+use tabled::{builder::Builder, Table, Tabled};
+
+#[test]
+fn table_to_json_test() {
+    #[derive(Tabled)]
+    struct Language {
+        name: &'static str,
+        designed_by: &'static str,
+        invented_year: usize,
+    }
+
+    let languages = vec![
+        Language {
+            name: "C",
+            designed_by: "Dennis Ritchie",
+            invented_year: 1972,
+        },
+        Language {
+            name: "Rust",
+            designed_by: "Graydon Hoare",
+            invented_year: 2010,
+        },
+    ];
+
+    let table = Table::new(languages);
+
+    let value = table_to_json(&table, &JsonOptions::new());
+
+    assert_eq!(
+        value,
+        json!([
+            {"name": "C", "designed_by": "Dennis Ritchie", "invented_year": "1972"},
+            {"name": "Rust", "designed_by": "Graydon Hoare", "invented_year": "2010"},
+        ])
+    );
+
+    let value = table_to_json(&table, JsonOptions::new().infer_types());
+
+    assert_eq!(
+        value,
+        json!([
+            {"name": "C", "designed_by": "Dennis Ritchie", "invented_year": 1972},
+            {"name": "Rust", "designed_by": "Graydon Hoare", "invented_year": 2010},
+        ])
+    );
+}
+
+#[test]
+fn table_to_json_infer_types_test() {
+    let mut builder = Builder::default();
+    builder.set_columns(["a", "b", "c", "d", "e", "f"]);
+    builder.add_record(["-1", "1.5", "18446744073709551615", "inf", "True", ""]);
+    let table = builder.build();
+
+    let value = table_to_json(&table, JsonOptions::new().infer_types());
+
+    assert_eq!(
+        value,
+        json!([{
+            "a": -1,
+            "b": 1.5,
+            "c": 18446744073709551615u64,
+            "d": "inf",
+            "e": "True",
+            "f": null,
+        }])
+    );
+}
+
+#[test]
+fn table_to_json_key_value_test() {
+    let mut builder = Builder::default();
+    builder.add_record(["name", "John Doe"]);
+    builder.add_record(["age", "43"]);
+    let table = builder.build();
+
+    let value = table_to_json(&table, JsonOptions::new().key_value().infer_types());
+
+    assert_eq!(value, json!({"name": "John Doe", "age": 43}));
+
+    let mut builder = Builder::default();
+    builder.add_record(["phones", "+44 1234567", "+44 2345678"]);
+    let table = builder.build();
+
+    let value = table_to_json(&table, JsonOptions::new().key_value());
+
+    assert_eq!(value, json!({"phones": ["+44 1234567", "+44 2345678"]}));
+}
+
+#[test]
+fn table_to_json_empty_test() {
+    let table = Builder::default().build();
+
+    assert_eq!(table_to_json(&table, &JsonOptions::new()), json!([]));
+    assert_eq!(
+        table_to_json(&table, JsonOptions::new().key_value()),
+        json!({})
+    );
+
+    let mut builder = Builder::default();
+    builder.set_columns(["name", "age"]);
+    let table = builder.build();
+
+    assert_eq!(table_to_json(&table, &JsonOptions::new()), json!([]));
+}
+
+#[test]
+fn table_to_json_infer_types_exact_numbers_test() {
+    let mut builder = Builder::default();
+    builder.set_columns(["n", "zip", "e", "ws"]);
+    builder.add_record(["+5", "02134", "1e3", " 7"]);
+    let table = builder.build();
+
+    let value = table_to_json(&table, JsonOptions::new().infer_types());
+
+    assert_eq!(
+        value,
+        json!([{"n": "+5", "zip": "02134", "e": 1000.0, "ws": " 7"}])
+    );
+}
+
+#[test]
+fn table_to_json_duplicate_keys_test() {
+    let mut builder = Builder::default();
+    builder.set_columns(["a", "a", "a_2", "b"]);
+    builder.add_record(["1", "2", "3", "4"]);
+    let table = builder.build();
+
+    let value = table_to_json(&table, &JsonOptions::new());
+
+    assert_eq!(
+        value,
+        json!([{"a": "1", "a_2": "2", "a_2_2": "3", "b": "4"}])
+    );
+
+    let mut builder = Builder::default();
+    builder.add_record(["name", "John Doe"]);
+    builder.add_record(["name", "Jane Doe"]);
+    let table = builder.build();
+
+    let value = table_to_json(&table, JsonOptions::new().key_value());
+
+    assert_eq!(value, json!({"name": "John Doe", "name_2": "Jane Doe"}));
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "color")]
+
+use json_to_table::{json_to_table, Theme};
+use serde_json::json;
+use tabled::{color::Color, Style};
+
+#[test]
+fn theme_jq_test() {
+    let value = json!({"a": [true, null, "x\ny"], "b": {"c": 1.5}});
+
+    let table = json_to_table(&value).set_theme(Theme::jq()).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+---+---------------+\n",
+            "| \u{1b}[34ma\u{1b}[39m | +--------+    |\n",
+            "|   | |  true  |    |\n",
+            "|   | +--------+    |\n",
+            "|   | |        |    |\n",
+            "|   | +--------+    |\n",
+            "|   | |  \u{1b}[32mx\u{1b}[39m     |    |\n",
+            "|   | |  \u{1b}[32my\u{1b}[39m     |    |\n",
+            "|   | +--------+    |\n",
+            "+---+---------------+\n",
+            "| \u{1b}[34mb\u{1b}[39m | +---+-------+ |\n",
+            "|   | | \u{1b}[34mc\u{1b}[39m |  1.5  | |\n",
+            "|   | +---+-------+ |\n",
+            "+---+---------------+",
+        )
+    );
+}
+
+#[test]
+fn theme_border_colors_test() {
+    let value = json!({"a": [true], "b": {"c": 1.5}});
+
+    let mut theme = Theme::new();
+    theme.set_borders([Color::FG_RED, Color::FG_BLUE]);
+
+    let table = json_to_table(&value)
+        .set_style(Style::ascii())
+        .set_theme(theme)
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "\u{1b}[31m+---+---------------+\u{1b}[39m\n",
+            "\u{1b}[31m|\u{1b}[39m a \u{1b}[31m|\u{1b}[39m \u{1b}[34m+--------+\u{1b}[39m    \u{1b}[31m|\u{1b}[39m\n",
+            "\u{1b}[31m|\u{1b}[39m   \u{1b}[31m|\u{1b}[39m \u{1b}[34m|\u{1b}[39m  true  \u{1b}[34m|\u{1b}[39m    \u{1b}[31m|\u{1b}[39m\n",
+            "\u{1b}[31m|\u{1b}[39m   \u{1b}[31m|\u{1b}[39m \u{1b}[34m+--------+\u{1b}[39m    \u{1b}[31m|\u{1b}[39m\n",
+            "\u{1b}[31m+---+---------------+\u{1b}[39m\n",
+            "\u{1b}[31m|\u{1b}[39m b \u{1b}[31m|\u{1b}[39m \u{1b}[34m+---+-------+\u{1b}[39m \u{1b}[31m|\u{1b}[39m\n",
+            "\u{1b}[31m|\u{1b}[39m   \u{1b}[31m|\u{1b}[39m \u{1b}[34m|\u{1b}[39m c \u{1b}[34m|\u{1b}[39m  1.5  \u{1b}[34m|\u{1b}[39m \u{1b}[31m|\u{1b}[39m\n",
+            "\u{1b}[31m|\u{1b}[39m   \u{1b}[31m|\u{1b}[39m \u{1b}[34m+---+-------+\u{1b}[39m \u{1b}[31m|\u{1b}[39m\n",
+            "\u{1b}[31m+---+---------------+\u{1b}[39m",
+        )
+    );
+}
+
+#[test]
+fn theme_collapsed_test() {
+    let value = json!({"a": [true, null, "x\ny"], "b": {"c": 1.5}});
+
+    let mut theme = Theme::jq();
+    theme
+        .set_number(Color::FG_RED)
+        .set_boolean(Color::FG_YELLOW);
+
+    let table = json_to_table(&value)
+        .collapse()
+        .set_theme(theme)
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+---+---------+\n",
+            "| \u{1b}[34ma\u{1b}[39m | \u{1b}[33mtrue\u{1b}[39m    |\n",
+            "|   +---------+\n",
+            "|   |         |\n",
+            "|   +---------+\n",
+            "|   | \u{1b}[32mx\u{1b}[39m       |\n",
+            "|   | \u{1b}[32my\u{1b}[39m       |\n",
+            "+---+---+-----+\n",
+            "| \u{1b}[34mb\u{1b}[39m | \u{1b}[34mc\u{1b}[39m | \u{1b}[31m1.5\u{1b}[39m |\n",
+            "+---+---+-----+",
+        )
+    );
+}
+
+#[test]
+fn theme_flatten_test() {
+    let value = json!({"a": [true, null, "x"], "b": {"c": 1.5}});
+
+    let table = json_to_table(&value)
+        .flatten()
+        .set_style(Style::blank())
+        .set_theme(Theme::jq())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " \u{1b}[34ma[0]\u{1b}[39m   true \n",
+            " \u{1b}[34ma[1]\u{1b}[39m        \n",
+            " \u{1b}[34ma[2]\u{1b}[39m   \u{1b}[32mx\u{1b}[39m    \n",
+            " \u{1b}[34mb.c\u{1b}[39m    1.5  ",
+        )
+    );
+}
+
+#[test]
+fn theme_show_types_test() {
+    let value = json!({"a": null, "b": 1});
+
+    let mut theme = Theme::new();
+    theme.set_null(Color::FG_RED);
+
+    let table = json_to_table(&value)
+        .flatten()
+        .show_types()
+        .set_style(Style::blank())
+        .set_theme(theme)
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" a       \u{1b}[31mnull\u{1b}[39m   \n", " b   1   number ",)
+    );
+}
@@ -0,0 +1,113 @@
+#[cfg(feature = "toml")]
+#[test]
+fn toml_test() {
+    use json_to_table::JsonTable;
+
+    let value: toml::Value = toml::from_str(concat!(
+        "name = \"server\"\n",
+        "ports = [8080, 8081]\n",
+        "\n",
+        "[owner]\n",
+        "since = 1979-05-27\n",
+    ))
+    .unwrap();
+
+    let table = JsonTable::new(&value).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-------+--------------------------+\n",
+            "| name  |  server                  |\n",
+            "+-------+--------------------------+\n",
+            "| owner | +-------+--------------+ |\n",
+            "|       | | since |  1979-05-27  | |\n",
+            "|       | +-------+--------------+ |\n",
+            "+-------+--------------------------+\n",
+            "| ports | +--------+               |\n",
+            "|       | |  8080  |               |\n",
+            "|       | +--------+               |\n",
+            "|       | |  8081  |               |\n",
+            "|       | +--------+               |\n",
+            "+-------+--------------------------+",
+        )
+    );
+
+    let table = JsonTable::new(&value).collapse().to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-------+--------------------+\n",
+            "| name  | server             |\n",
+            "+-------+-------+------------+\n",
+            "| owner | since | 1979-05-27 |\n",
+            "+-------+-------+------------+\n",
+            "| ports | 8080               |\n",
+            "|       +--------------------+\n",
+            "|       | 8081               |\n",
+            "+-------+--------------------+",
+        )
+    );
+}
+
+#[cfg(feature = "serde_yaml")]
+#[test]
+fn yaml_test() {
+    use json_to_table::JsonTable;
+
+    let value: serde_yaml::Value = serde_yaml::from_str(concat!(
+        "name: server\n",
+        "ports:\n",
+        "  - 8080\n",
+        "  - 8081\n",
+        "1: one\n",
+        "enabled: !flag true\n",
+        "empty: ~\n",
+    ))
+    .unwrap();
+
+    let table = JsonTable::new(&value).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+---------+------------+\n",
+            "| name    |  server    |\n",
+            "+---------+------------+\n",
+            "| ports   | +--------+ |\n",
+            "|         | |  8080  | |\n",
+            "|         | +--------+ |\n",
+            "|         | |  8081  | |\n",
+            "|         | +--------+ |\n",
+            "+---------+------------+\n",
+            "| 1       |  one       |\n",
+            "+---------+------------+\n",
+            "| enabled |  true      |\n",
+            "+---------+------------+\n",
+            "| empty   |            |\n",
+            "+---------+------------+",
+        )
+    );
+
+    let table = JsonTable::new(&value).flatten().to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+----------+--------+\n",
+            "| name     | server |\n",
+            "+----------+--------+\n",
+            "| ports[0] | 8080   |\n",
+            "+----------+--------+\n",
+            "| ports[1] | 8081   |\n",
+            "+----------+--------+\n",
+            "| 1        | one    |\n",
+            "+----------+--------+\n",
+            "| enabled  | true   |\n",
+            "+----------+--------+\n",
+            "| empty    |        |\n",
+            "+----------+--------+",
+        )
+    );
+}
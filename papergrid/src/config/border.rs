@@ -44,6 +44,20 @@ impl<T> Border<T> {
         }
     }
 
+    /// Converts each set symbol via a given function.
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Border<U> {
+        Border {
+            top: self.top.map(&f),
+            bottom: self.bottom.map(&f),
+            left: self.left.map(&f),
+            right: self.right.map(&f),
+            left_top_corner: self.left_top_corner.map(&f),
+            left_bottom_corner: self.left_bottom_corner.map(&f),
+            right_top_corner: self.right_top_corner.map(&f),
+            right_bottom_corner: self.right_bottom_corner.map(&f),
+        }
+    }
+
     /// Checks whether any side is set.
     pub fn is_empty(&self) -> bool {
         self.top.is_none()
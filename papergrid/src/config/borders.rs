@@ -300,6 +300,74 @@ impl<T: std::fmt::Debug> BordersConfig<T> {
             || self.is_vertical_set(col, count_cols)
     }
 
+    /// Returns a max width of symbols which may be rendered on a vertical line,
+    /// `None` is returned if there's no such symbols.
+    pub(crate) fn get_vertical_width(
+        &self,
+        col: usize,
+        count_cols: usize,
+        width: impl Fn(&T) -> usize,
+    ) -> Option<usize> {
+        let use_left = col == 0;
+        let use_right = col == count_cols;
+
+        let frame = if use_left {
+            [
+                &self.borders.vertical_left,
+                &self.borders.top_left,
+                &self.borders.bottom_left,
+                &self.borders.horizontal_left,
+            ]
+        } else if use_right {
+            [
+                &self.borders.vertical_right,
+                &self.borders.top_right,
+                &self.borders.bottom_right,
+                &self.borders.horizontal_right,
+            ]
+        } else {
+            [
+                &self.borders.vertical,
+                &self.borders.top_intersection,
+                &self.borders.bottom_intersection,
+                &self.borders.intersection,
+            ]
+        };
+
+        let vertical_line = self
+            .verticals
+            .get(&col)
+            .into_iter()
+            .flat_map(|l| [&l.main, &l.intersection, &l.top, &l.bottom]);
+
+        let horizontal_lines = self.horizontals.values().map(|l| {
+            if use_left {
+                &l.left
+            } else if use_right {
+                &l.right
+            } else {
+                &l.intersection
+            }
+        });
+
+        let cells = self
+            .cells
+            .vertical
+            .iter()
+            .chain(self.cells.intersection.iter())
+            .filter(|(pos, _)| pos.1 == col)
+            .map(|(_, c)| c);
+
+        IntoIterator::into_iter(frame)
+            .chain(vertical_line)
+            .chain(horizontal_lines)
+            .flatten()
+            .chain(cells)
+            .chain(self.global.as_ref())
+            .map(width)
+            .max()
+    }
+
     fn is_horizontal_set(&self, row: usize, count_rows: usize) -> bool {
         (row == 0 && self.layout.top)
             || (row == count_rows && self.layout.bottom)
@@ -371,6 +439,27 @@ pub struct Borders<T = char> {
 }
 
 impl<T> Borders<T> {
+    /// Converts each set symbol via a given function.
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Borders<U> {
+        Borders {
+            top: self.top.map(&f),
+            top_left: self.top_left.map(&f),
+            top_right: self.top_right.map(&f),
+            top_intersection: self.top_intersection.map(&f),
+            bottom: self.bottom.map(&f),
+            bottom_left: self.bottom_left.map(&f),
+            bottom_right: self.bottom_right.map(&f),
+            bottom_intersection: self.bottom_intersection.map(&f),
+            horizontal: self.horizontal.map(&f),
+            horizontal_left: self.horizontal_left.map(&f),
+            horizontal_right: self.horizontal_right.map(&f),
+            vertical: self.vertical.map(&f),
+            vertical_left: self.vertical_left.map(&f),
+            vertical_right: self.vertical_right.map(&f),
+            intersection: self.intersection.map(&f),
+        }
+    }
+
     /// Verifies if borders has left line set on the frame.
     pub const fn has_left(&self) -> bool {
         self.vertical_left.is_some()
@@ -453,6 +542,16 @@ pub struct HorizontalLine<T> {
 }
 
 impl<T> HorizontalLine<T> {
+    /// Converts each set symbol via a given function.
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> HorizontalLine<U> {
+        HorizontalLine {
+            main: self.main.map(&f),
+            intersection: self.intersection.map(&f),
+            left: self.left.map(&f),
+            right: self.right.map(&f),
+        }
+    }
+
     /// Verifies if the line has any setting set.
     pub const fn is_empty(&self) -> bool {
         self.main.is_none()
@@ -476,6 +575,16 @@ pub struct VerticalLine<T> {
 }
 
 impl<T> VerticalLine<T> {
+    /// Converts each set symbol via a given function.
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> VerticalLine<U> {
+        VerticalLine {
+            main: self.main.map(&f),
+            intersection: self.intersection.map(&f),
+            top: self.top.map(&f),
+            bottom: self.bottom.map(&f),
+        }
+    }
+
     /// Verifies if the line has any setting set.
     pub const fn is_empty(&self) -> bool {
         self.main.is_none()
@@ -6,6 +6,7 @@ mod entity_map;
 mod formatting;
 mod offset;
 mod sides;
+mod symbol;
 
 use std::collections::HashMap;
 
@@ -17,6 +18,7 @@ pub use self::{
     formatting::Formatting,
     offset::Offset,
     sides::{Indent, Sides},
+    symbol::BorderSymbol,
 };
 
 #[cfg(feature = "color")]
@@ -38,8 +40,8 @@ pub struct GridConfig {
     formatting: EntityMap<Formatting>,
     span_columns: HashMap<Position, usize>,
     span_rows: HashMap<Position, usize>,
-    borders: BordersConfig<char>,
-    borders_missing_char: char,
+    borders: BordersConfig<BorderSymbol>,
+    borders_missing_char: BorderSymbol,
     override_horizontal_lines: HashMap<usize, (String, Offset)>,
     override_horizontal_borders: HashMap<Position, HashMap<Offset, char>>,
     override_vertical_borders: HashMap<Position, HashMap<Offset, char>>,
@@ -69,7 +71,7 @@ impl Default for GridConfig {
             alignment_h: EntityMap::new(AlignmentHorizontal::Left),
             alignment_v: EntityMap::new(AlignmentVertical::Top),
             borders: BordersConfig::default(),
-            borders_missing_char: ' ',
+            borders_missing_char: BorderSymbol::from_char(' '),
             span_columns: HashMap::default(),
             span_rows: HashMap::default(),
             override_horizontal_lines: HashMap::default(),
@@ -174,18 +176,26 @@ impl GridConfig {
     }
 
     /// Set the [`Borders`] value as currect one.
-    pub fn set_borders(&mut self, borders: Borders<char>) {
-        self.borders.set_borders(borders);
+    ///
+    /// The borders may consist of [`char`]s or [`BorderSymbol`]s of any width.
+    /// A vertical line takes the width of its widest symbol,
+    /// so narrower symbols of the line are centered and padded by spaces,
+    /// and intersections are padded by a horizontal symbol of their line.
+    pub fn set_borders<T>(&mut self, borders: Borders<T>)
+    where
+        T: Into<BorderSymbol>,
+    {
+        self.borders.set_borders(borders.map(Into::into));
     }
 
     /// Gets a global border value if set.
-    pub fn get_global_border(&self) -> Option<&char> {
+    pub fn get_global_border(&self) -> Option<&BorderSymbol> {
         self.borders.get_global()
     }
 
-    /// Set the all [`Borders`] values to a char.
-    pub fn set_global_border(&mut self, c: char) {
-        self.borders.set_global(c);
+    /// Set the all [`Borders`] values to a symbol.
+    pub fn set_global_border(&mut self, c: impl Into<BorderSymbol>) {
+        self.borders.set_global(c.into());
     }
 
     /// Set tab width in spaces.
@@ -199,7 +209,7 @@ impl GridConfig {
     }
 
     /// Returns a current [`Borders`] structure.
-    pub fn get_borders(&self) -> &Borders<char> {
+    pub fn get_borders(&self) -> &Borders<BorderSymbol> {
         self.borders.get_borders()
     }
 
@@ -207,8 +217,12 @@ impl GridConfig {
     ///
     /// Row `0` means the top row.
     /// Row `grid.count_rows()` means the bottom row.
-    pub fn set_horizontal_line(&mut self, row: usize, line: HorizontalLine<char>) {
-        self.borders.insert_horizontal_line(row, line);
+    pub fn set_horizontal_line<T>(&mut self, row: usize, line: HorizontalLine<T>)
+    where
+        T: Into<BorderSymbol>,
+    {
+        self.borders
+            .insert_horizontal_line(row, line.map(Into::into));
     }
 
     /// Sets off the border line by row index if any were set
@@ -223,7 +237,7 @@ impl GridConfig {
     ///
     /// Row `0` means the top row.
     /// Row `grid.count_rows()` means the bottom row.
-    pub fn get_vertical_line(&self, row: usize) -> Option<&VerticalLine<char>> {
+    pub fn get_vertical_line(&self, row: usize) -> Option<&VerticalLine<BorderSymbol>> {
         self.borders.get_vertical_line(row)
     }
 
@@ -231,8 +245,11 @@ impl GridConfig {
     ///
     /// Row `0` means the top row.
     /// Row `grid.count_rows()` means the bottom row.
-    pub fn set_vertical_line(&mut self, row: usize, line: VerticalLine<char>) {
-        self.borders.insert_vertical_line(row, line);
+    pub fn set_vertical_line<T>(&mut self, row: usize, line: VerticalLine<T>)
+    where
+        T: Into<BorderSymbol>,
+    {
+        self.borders.insert_vertical_line(row, line.map(Into::into));
     }
 
     /// Sets off the border line by row index if any were set
@@ -247,7 +264,7 @@ impl GridConfig {
     ///
     /// Row `0` means the top row.
     /// Row `grid.count_rows()` means the bottom row.
-    pub fn get_horizontal_line(&self, row: usize) -> Option<&HorizontalLine<char>> {
+    pub fn get_horizontal_line(&self, row: usize) -> Option<&HorizontalLine<BorderSymbol>> {
         self.borders.get_horizontal_line(row)
     }
 
@@ -318,7 +335,7 @@ impl GridConfig {
 
     /// Checks if there any char in a horizontal border being overridden.
     pub fn is_overridden_horizontal(&self, pos: Position) -> bool {
-        self.override_horizontal_borders.get(&pos).is_some()
+        self.override_horizontal_borders.contains_key(&pos)
     }
 
     /// Removes a list of overridden chars in a horizontal border.
@@ -365,7 +382,7 @@ impl GridConfig {
 
     /// Checks if there any char in a horizontal border being overridden.
     pub fn is_overridden_vertical(&self, pos: Position) -> bool {
-        self.override_vertical_borders.get(&pos).is_some()
+        self.override_vertical_borders.contains_key(&pos)
     }
 
     /// Removes a list of overridden chars in a horizontal border.
@@ -452,8 +469,11 @@ impl GridConfig {
     }
 
     /// Set border set a border value to all cells in [`Entity`].
-    pub fn set_border(&mut self, pos: Position, border: Border) {
-        self.borders.insert_border(pos, border);
+    pub fn set_border<T>(&mut self, pos: Position, border: Border<T>)
+    where
+        T: Into<BorderSymbol>,
+    {
+        self.borders.insert_border(pos, border.map(Into::into));
     }
 
     /// Sets off all borders possible on the [`Entity`].
@@ -466,8 +486,8 @@ impl GridConfig {
     /// Set a character which will be used in case any misconfiguration of borders.
     ///
     /// It will be usde for example when you set a left char for border frame and top but didn't set a top left corner.
-    pub fn set_borders_missing(&mut self, c: char) {
-        self.borders_missing_char = c;
+    pub fn set_borders_missing(&mut self, c: impl Into<BorderSymbol>) {
+        self.borders_missing_char = c.into();
     }
 
     /// Calculates an amount of vertical lines would present on the grid.
@@ -479,6 +499,31 @@ impl GridConfig {
             .count()
     }
 
+    /// Returns a display width of a vertical line,
+    /// which is the widest symbol which may be rendered on it.
+    ///
+    /// It's `0` if there's no such line on the grid.
+    ///
+    /// grid: crate::Grid
+    pub fn get_vertical_width(&self, col: usize, count_columns: usize) -> usize {
+        if !self.has_vertical(col, count_columns) {
+            return 0;
+        }
+
+        self.borders
+            .get_vertical_width(col, count_columns, BorderSymbol::width)
+            .unwrap_or_else(|| self.borders_missing_char.width())
+    }
+
+    /// Calculates a total display width of vertical lines on the grid.
+    ///
+    /// grid: crate::Grid
+    pub fn count_vertical_width(&self, count_columns: usize) -> usize {
+        (0..=count_columns)
+            .map(|col| self.get_vertical_width(col, count_columns))
+            .sum()
+    }
+
     /// Calculates an amount of horizontal lines would present on the grid.
     ///
     /// grid: crate::Grid
@@ -489,14 +534,14 @@ impl GridConfig {
     }
 
     /// Returns a border of a cell.
-    pub fn get_border(&self, pos: Position, shape: (usize, usize)) -> Border<char> {
-        self.borders.get_border(pos, shape.0, shape.1).copied()
+    pub fn get_border(&self, pos: Position, shape: (usize, usize)) -> Border<BorderSymbol> {
+        self.borders.get_border(pos, shape.0, shape.1).cloned()
     }
 
     /// Gets a vertical character which would be rendered on the grid.
     ///
     /// grid: crate::Grid
-    pub fn get_vertical(&self, pos: Position, count_columns: usize) -> Option<&char> {
+    pub fn get_vertical(&self, pos: Position, count_columns: usize) -> Option<&BorderSymbol> {
         let c = self.borders.get_vertical(pos, count_columns);
         if c.is_some() {
             return c;
@@ -512,7 +557,7 @@ impl GridConfig {
     /// Gets a horizontal character which would be rendered on the grid.
     ///
     /// grid: crate::Grid
-    pub fn get_horizontal(&self, pos: Position, count_rows: usize) -> Option<&char> {
+    pub fn get_horizontal(&self, pos: Position, count_rows: usize) -> Option<&BorderSymbol> {
         let c = self.borders.get_horizontal(pos, count_rows);
         if c.is_some() {
             return c;
@@ -528,7 +573,7 @@ impl GridConfig {
     /// Gets an intersection character which would be rendered on the grid.
     ///
     /// grid: crate::Grid
-    pub fn get_intersection(&self, pos: Position, shape: (usize, usize)) -> Option<&char> {
+    pub fn get_intersection(&self, pos: Position, shape: (usize, usize)) -> Option<&BorderSymbol> {
         let c = self.borders.get_intersection(pos, shape.0, shape.1);
         if c.is_some() {
             return c;
@@ -666,7 +711,7 @@ fn set_cell_row_span(cfg: &mut GridConfig, (mut row, col): Position, mut span: u
 
 fn closest_visible_row(cfg: &GridConfig, mut pos: Position) -> Option<usize> {
     loop {
-        if cfg.is_cell_visible(pos, (std::usize::MAX, std::usize::MAX)) {
+        if cfg.is_cell_visible(pos, (usize::MAX, usize::MAX)) {
             return Some(pos.0);
         }
 
@@ -706,7 +751,7 @@ fn set_cell_column_span(cfg: &mut GridConfig, (row, mut col): Position, mut span
 
 fn closest_visible_column(cfg: &GridConfig, mut pos: Position) -> Option<usize> {
     loop {
-        if cfg.is_cell_visible(pos, (std::usize::MAX, std::usize::MAX)) {
+        if cfg.is_cell_visible(pos, (usize::MAX, usize::MAX)) {
             return Some(pos.1);
         }
 
@@ -0,0 +1,120 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
+
+use crate::util::string_width;
+
+/// A symbol of a border.
+///
+/// It's either a [`char`] or a string, static or owned,
+/// so a border element may take any display width, like `" | "`, `"══"` or an emoji.
+///
+/// A symbol narrower than its vertical line is centered in it,
+/// see [`GridConfig::set_borders`].
+///
+/// [`GridConfig::set_borders`]: crate::GridConfig::set_borders
+///
+/// ```
+/// use papergrid::BorderSymbol;
+///
+/// assert_eq!(BorderSymbol::from('|').width(), 1);
+/// assert_eq!(BorderSymbol::from(" | ").width(), 3);
+/// assert_eq!(BorderSymbol::from("🦀").width(), 2);
+/// assert_eq!(BorderSymbol::from("=".repeat(2)).width(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BorderSymbol(SymbolKind);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum SymbolKind {
+    Char(char),
+    Text(Cow<'static, str>),
+}
+
+impl BorderSymbol {
+    /// Creates a symbol out of a [`char`].
+    pub const fn from_char(c: char) -> Self {
+        Self(SymbolKind::Char(c))
+    }
+
+    /// Creates a symbol out of a string.
+    ///
+    /// The string is expected to be a single line.
+    pub const fn from_static(text: &'static str) -> Self {
+        Self(SymbolKind::Text(Cow::Borrowed(text)))
+    }
+
+    /// Creates a symbol out of an owned string.
+    ///
+    /// The string is expected to be a single line.
+    pub fn from_string(text: String) -> Self {
+        Self(SymbolKind::Text(Cow::Owned(text)))
+    }
+
+    /// Returns a display width of the symbol.
+    pub fn width(&self) -> usize {
+        match &self.0 {
+            SymbolKind::Char(c) => unicode_width::UnicodeWidthChar::width(*c).unwrap_or(0),
+            SymbolKind::Text(text) => string_width(text),
+        }
+    }
+
+    /// Returns a [`char`] in case the symbol was built out of it.
+    pub const fn as_char(&self) -> Option<char> {
+        match self.0 {
+            SymbolKind::Char(c) => Some(c),
+            SymbolKind::Text(_) => None,
+        }
+    }
+
+    /// Returns a string in case the symbol was built out of it.
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.0 {
+            SymbolKind::Char(_) => None,
+            SymbolKind::Text(text) => Some(text),
+        }
+    }
+}
+
+impl fmt::Display for BorderSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            SymbolKind::Char(c) => f.write_char(*c),
+            SymbolKind::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+impl Default for BorderSymbol {
+    fn default() -> Self {
+        Self::from_char(char::default())
+    }
+}
+
+impl From<char> for BorderSymbol {
+    fn from(c: char) -> Self {
+        Self::from_char(c)
+    }
+}
+
+impl From<&'static str> for BorderSymbol {
+    fn from(text: &'static str) -> Self {
+        Self::from_static(text)
+    }
+}
+
+impl From<String> for BorderSymbol {
+    fn from(text: String) -> Self {
+        Self::from_string(text)
+    }
+}
+
+impl From<Cow<'static, str>> for BorderSymbol {
+    fn from(text: Cow<'static, str>) -> Self {
+        match text {
+            Cow::Borrowed(text) => Self::from_static(text),
+            Cow::Owned(text) => Self::from_string(text),
+        }
+    }
+}
@@ -177,6 +177,6 @@ fn count_borders_in_range(
 ) -> usize {
     (start..end)
         .skip(1)
-        .filter(|&i| cfg.has_vertical(i, count_columns))
-        .count()
+        .map(|i| cfg.get_vertical_width(i, count_columns))
+        .sum()
 }
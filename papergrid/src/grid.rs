@@ -9,10 +9,10 @@ use std::{
 use crate::{
     estimation::Estimate,
     records::Records,
-    util::{get_lines, spplit_str_at, string_trim, string_width},
+    util::{get_lines, split_at_pos, spplit_str_at, string_trim, string_width},
     width::{CfgWidthFunction, WidthFunc},
-    AlignmentHorizontal, AlignmentVertical, Formatting, GridConfig, Indent, Offset, Padding,
-    Position,
+    AlignmentHorizontal, AlignmentVertical, BorderSymbol, Formatting, GridConfig, Indent, Offset,
+    Padding, Position,
 };
 
 #[cfg(feature = "color")]
@@ -93,7 +93,8 @@ mod print_general {
         H: Estimate<R>,
         R: Records,
     {
-        let total_width = total_width(cfg, records, width);
+        let vertical_widths = vertical_widths(cfg, records);
+        let total_width = total_width(&vertical_widths, width);
         let total_width_with_margin =
             total_width + cfg.get_margin().left.size + cfg.get_margin().right.size;
 
@@ -115,7 +116,7 @@ mod print_general {
                 }
 
                 print_margin_left(f, cfg, table_line, total_height)?;
-                print_split_line(f, cfg, records, width, row, total_width)?;
+                print_split_line(f, cfg, records, width, &vertical_widths, row, total_width)?;
                 print_margin_right(f, cfg, table_line, total_height)?;
 
                 if count_lines > 0 {
@@ -135,7 +136,15 @@ mod print_general {
                 print_margin_left(f, cfg, table_line, total_height)?;
 
                 for col in 0..records.count_columns() {
-                    print_vertical_char(f, cfg, records, (row, col), i, count_lines)?;
+                    print_vertical_char(
+                        f,
+                        cfg,
+                        records,
+                        (row, col),
+                        &vertical_widths,
+                        i,
+                        count_lines,
+                    )?;
 
                     let width = width.get(col).unwrap();
                     let height = height.get(row).unwrap();
@@ -143,7 +152,15 @@ mod print_general {
 
                     let is_last_column = col + 1 == records.count_columns();
                     if is_last_column {
-                        print_vertical_char(f, cfg, records, (row, col + 1), i, count_lines)?;
+                        print_vertical_char(
+                            f,
+                            cfg,
+                            records,
+                            (row, col + 1),
+                            &vertical_widths,
+                            i,
+                            count_lines,
+                        )?;
                     }
                 }
 
@@ -162,7 +179,8 @@ mod print_general {
         if has_horizontal(cfg, records, records.count_rows()) {
             f.write_char('\n')?;
             print_margin_left(f, cfg, table_line, total_height)?;
-            print_split_line(f, cfg, records, width, records.count_rows(), total_width)?;
+            let row = records.count_rows();
+            print_split_line(f, cfg, records, width, &vertical_widths, row, total_width)?;
             print_margin_right(f, cfg, table_line, total_height)?;
         }
 
@@ -179,6 +197,7 @@ mod print_general {
         cfg: &GridConfig,
         records: &R,
         width_ctrl: &W,
+        vertical_widths: &[usize],
         row: usize,
         total_width: usize,
     ) -> fmt::Result
@@ -204,9 +223,11 @@ mod print_general {
             if col == 0 {
                 let left = cfg.get_intersection((row, col), shape);
                 if let Some(c) = left {
+                    let border_width = vertical_widths[col];
                     if i >= override_text_pos && !override_text.is_empty() {
-                        let (c, rest) = spplit_str_at(&override_text, 1);
+                        let (c, rest) = spplit_str_at(&override_text, border_width);
                         f.write_str(&c)?;
+                        repeat_char(f, ' ', border_width.saturating_sub(string_width(&c)))?;
                         override_text = rest.into_owned();
                         if string_width(&override_text) == 0 {
                             override_text = String::new()
@@ -221,8 +242,9 @@ mod print_general {
                             }
                         }
 
-                        f.write_char(*c)?;
-                        i += 1;
+                        let fill = get_horizontal(cfg, records, (row, col));
+                        print_border_symbol(f, c, border_width, fill)?;
+                        i += border_width;
                     }
                 }
             }
@@ -248,7 +270,7 @@ mod print_general {
                             )?;
                         }
 
-                        print_horizontal_border(f, cfg, (row, col), width, *c)?;
+                        print_horizontal_border(f, cfg, (row, col), width, c)?;
                     }
                     None => repeat_char(f, DEFAULT_BORDER_HORIZONTAL_CHAR, width)?,
                 }
@@ -282,7 +304,7 @@ mod print_general {
                             )?;
                         }
 
-                        print_horizontal_border(f, cfg, (row, col), width, *c)?;
+                        print_horizontal_border(f, cfg, (row, col), width, c)?;
                     }
                     None => repeat_char(f, DEFAULT_BORDER_HORIZONTAL_CHAR, width)?,
                 }
@@ -292,9 +314,11 @@ mod print_general {
 
             let right = get_intersection(cfg, records, (row, col + 1));
             if let Some(c) = right {
+                let border_width = vertical_widths[col + 1];
                 if i >= override_text_pos && !override_text.is_empty() {
-                    let (c, rest) = spplit_str_at(&override_text, 1);
+                    let (c, rest) = spplit_str_at(&override_text, border_width);
                     f.write_str(&c)?;
+                    repeat_char(f, ' ', border_width.saturating_sub(string_width(&c)))?;
                     override_text = rest.into_owned();
                     if string_width(&override_text) == 0 {
                         override_text = String::new()
@@ -309,8 +333,10 @@ mod print_general {
                         )?;
                     }
 
-                    f.write_char(*c)?;
-                    i += 1;
+                    let fill_col = cmp::min(col + 1, records.count_columns() - 1);
+                    let fill = get_horizontal(cfg, records, (row, fill_col));
+                    print_border_symbol(f, c, border_width, fill)?;
+                    i += border_width;
                 }
             }
         }
@@ -343,7 +369,8 @@ mod print_spanned {
     {
         let shape = (records.count_rows(), records.count_columns());
 
-        let total_width = total_width(cfg, records, width);
+        let vertical_widths = vertical_widths(cfg, records);
+        let total_width = total_width(&vertical_widths, width);
         let total_width_with_margin =
             total_width + cfg.get_margin().left.size + cfg.get_margin().right.size;
 
@@ -365,7 +392,16 @@ mod print_spanned {
                 }
 
                 print_margin_left(f, cfg, table_line, total_height)?;
-                print_split_line(f, cfg, records, width, height, row, total_width)?;
+                print_split_line(
+                    f,
+                    cfg,
+                    records,
+                    width,
+                    height,
+                    &vertical_widths,
+                    row,
+                    total_width,
+                )?;
                 print_margin_right(f, cfg, table_line, total_height)?;
 
                 if count_lines > 0 {
@@ -387,7 +423,15 @@ mod print_spanned {
                 for col in 0..records.count_columns() {
                     if !cfg.is_cell_covered_by_both_spans((row, col), shape) {
                         if cfg.is_cell_covered_by_row_span((row, col), shape) {
-                            print_vertical_char(f, cfg, records, (row, col), i, count_lines)?;
+                            print_vertical_char(
+                                f,
+                                cfg,
+                                records,
+                                (row, col),
+                                &vertical_widths,
+                                i,
+                                count_lines,
+                            )?;
 
                             // means it's part of other a spanned cell
                             // so. we just need to use line from other cell.
@@ -413,14 +457,30 @@ mod print_spanned {
                                 line,
                             )?;
                         } else if !cfg.is_cell_covered_by_column_span((row, col), shape) {
-                            print_vertical_char(f, cfg, records, (row, col), i, count_lines)?;
+                            print_vertical_char(
+                                f,
+                                cfg,
+                                records,
+                                (row, col),
+                                &vertical_widths,
+                                i,
+                                count_lines,
+                            )?;
                             print_cell_line(f, cfg, records, width, height, (row, col), i)?;
                         }
                     }
 
                     let is_last_column = col + 1 == records.count_columns();
                     if is_last_column {
-                        print_vertical_char(f, cfg, records, (row, col + 1), i, count_lines)?;
+                        print_vertical_char(
+                            f,
+                            cfg,
+                            records,
+                            (row, col + 1),
+                            &vertical_widths,
+                            i,
+                            count_lines,
+                        )?;
                     }
                 }
 
@@ -440,7 +500,16 @@ mod print_spanned {
             f.write_char('\n')?;
             print_margin_left(f, cfg, table_line, total_height)?;
             let row = records.count_rows();
-            print_split_line(f, cfg, records, width, height, row, total_width)?;
+            print_split_line(
+                f,
+                cfg,
+                records,
+                width,
+                height,
+                &vertical_widths,
+                row,
+                total_width,
+            )?;
             print_margin_right(f, cfg, table_line, total_height)?;
         }
 
@@ -459,6 +528,7 @@ mod print_spanned {
         records: &R,
         width_ctrl: &W,
         height_ctrl: &H,
+        vertical_widths: &[usize],
         row: usize,
         total_width: usize,
     ) -> fmt::Result
@@ -485,9 +555,11 @@ mod print_spanned {
             if col == 0 {
                 let left = cfg.get_intersection((row, col), shape);
                 if let Some(c) = left {
+                    let border_width = vertical_widths[col];
                     if i >= override_text_pos && !override_text.is_empty() {
-                        let (c, rest) = spplit_str_at(&override_text, 1);
+                        let (c, rest) = spplit_str_at(&override_text, border_width);
                         f.write_str(&c)?;
+                        repeat_char(f, ' ', border_width.saturating_sub(string_width(&c)))?;
                         override_text = rest.into_owned();
                         if string_width(&override_text) == 0 {
                             override_text = String::new()
@@ -502,8 +574,9 @@ mod print_spanned {
                             }
                         }
 
-                        f.write_char(*c)?;
-                        i += 1;
+                        let fill = get_horizontal(cfg, records, (row, col));
+                        print_border_symbol(f, c, border_width, fill)?;
+                        i += border_width;
                     }
                 }
             }
@@ -562,7 +635,7 @@ mod print_spanned {
                                 )?;
                             }
 
-                            print_horizontal_border(f, cfg, (row, col), width, *c)?;
+                            print_horizontal_border(f, cfg, (row, col), width, c)?;
                         }
                         None => repeat_char(f, DEFAULT_BORDER_HORIZONTAL_CHAR, width)?,
                     }
@@ -595,7 +668,7 @@ mod print_spanned {
                             )?;
                         }
 
-                        print_horizontal_border(f, cfg, (row, col), width, *c)?;
+                        print_horizontal_border(f, cfg, (row, col), width, c)?;
                     }
                     None => repeat_char(f, DEFAULT_BORDER_HORIZONTAL_CHAR, width)?,
                 }
@@ -605,9 +678,11 @@ mod print_spanned {
 
             let right = get_intersection(cfg, records, (row, col + 1));
             if let Some(c) = right {
+                let border_width = vertical_widths[col + 1];
                 if i >= override_text_pos && !override_text.is_empty() {
-                    let (c, rest) = spplit_str_at(&override_text, 1);
+                    let (c, rest) = spplit_str_at(&override_text, border_width);
                     f.write_str(&c)?;
+                    repeat_char(f, ' ', border_width.saturating_sub(string_width(&c)))?;
                     override_text = rest.into_owned();
                     if string_width(&override_text) == 0 {
                         override_text = String::new()
@@ -622,8 +697,10 @@ mod print_spanned {
                         )?;
                     }
 
-                    f.write_char(*c)?;
-                    i += 1;
+                    let fill_col = cmp::min(col + 1, records.count_columns() - 1);
+                    let fill = get_horizontal(cfg, records, (row, fill_col));
+                    print_border_symbol(f, c, border_width, fill)?;
+                    i += border_width;
                 }
             }
         }
@@ -661,21 +738,120 @@ fn print_horizontal_border(
     cfg: &GridConfig,
     pos: Position,
     width: usize,
-    c: char,
+    symbol: &BorderSymbol,
 ) -> fmt::Result {
-    if cfg.is_overridden_horizontal(pos) {
+    if !cfg.is_overridden_horizontal(pos) {
+        return print_symbol_fill(f, symbol, width);
+    }
+
+    if let Some(c) = symbol.as_char().filter(|_| symbol.width() == 1) {
         for i in 0..width {
             let c = cfg.lookup_overridden_horizontal(pos, i, width).unwrap_or(c);
 
             f.write_char(c)?;
         }
-    } else {
-        repeat_char(f, c, width)?;
+
+        return Ok(());
+    }
+
+    // The symbol is printed by chunks of its width,
+    // so a chunk which has an overridden char is printed as the overridden chars padded by spaces.
+    let symbol_width = cmp::max(symbol.width(), 1);
+    let mut i = 0;
+    while i < width {
+        let chunk = cmp::min(symbol_width, width - i);
+        let is_overridden =
+            (i..i + chunk).any(|i| cfg.lookup_overridden_horizontal(pos, i, width).is_some());
+
+        if is_overridden {
+            for i in i..i + chunk {
+                let c = cfg.lookup_overridden_horizontal(pos, i, width);
+                f.write_char(c.unwrap_or(DEFAULT_BORDER_HORIZONTAL_CHAR))?;
+            }
+        } else {
+            print_symbol_fill(f, symbol, chunk)?;
+        }
+
+        i += chunk;
     }
 
     Ok(())
 }
 
+// Prints a symbol of a border which takes a given width,
+// the symbol is centered and the rest of the width is filled by a `fill` symbol,
+// or by spaces if there's none.
+fn print_border_symbol(
+    f: &mut fmt::Formatter<'_>,
+    symbol: &BorderSymbol,
+    width: usize,
+    fill: Option<&BorderSymbol>,
+) -> fmt::Result {
+    let rest = width.saturating_sub(symbol.width());
+    let left = rest / 2;
+    let right = rest - left;
+
+    let print_fill = |f: &mut fmt::Formatter<'_>, width| match fill {
+        Some(fill) => print_symbol_fill(f, fill, width),
+        None => repeat_char(f, DEFAULT_BORDER_HORIZONTAL_CHAR, width),
+    };
+
+    print_fill(f, left)?;
+    fmt::Display::fmt(symbol, f)?;
+    print_fill(f, right)
+}
+
+// Repeats a symbol so it takes exactly a given width,
+// a last part of the symbol which doesn't fit is cut.
+fn print_symbol_fill(
+    f: &mut fmt::Formatter<'_>,
+    symbol: &BorderSymbol,
+    width: usize,
+) -> fmt::Result {
+    let symbol_width = symbol.width();
+    if let Some(c) = symbol.as_char().filter(|_| symbol_width == 1) {
+        return repeat_char(f, c, width);
+    }
+
+    if symbol_width == 0 {
+        return repeat_char(f, DEFAULT_BORDER_HORIZONTAL_CHAR, width);
+    }
+
+    for _ in 0..width / symbol_width {
+        fmt::Display::fmt(symbol, f)?;
+    }
+
+    let rest = width % symbol_width;
+    let part = match symbol.as_str() {
+        Some(text) => cut_symbol(text, rest),
+        None => Cow::Borrowed(""),
+    };
+
+    f.write_str(&part)?;
+    repeat_char(
+        f,
+        DEFAULT_BORDER_HORIZONTAL_CHAR,
+        rest - string_width(&part),
+    )
+}
+
+// Cuts a text to a given width,
+// but unlike [`crate::util::cut_str`] a split wide character is dropped.
+fn cut_symbol(text: &str, width: usize) -> Cow<'_, str> {
+    #[cfg(feature = "color")]
+    {
+        let stripped = ansi_str::AnsiStr::ansi_strip(text);
+        let (length, _, _) = split_at_pos(&stripped, width);
+        ansi_str::AnsiStr::ansi_cut(text, ..length)
+    }
+
+    #[cfg(not(feature = "color"))]
+    {
+        let (length, _, _) = split_at_pos(text, width);
+        Cow::Borrowed(&text[..length])
+    }
+}
+
 fn print_cell_line<R>(
     f: &mut fmt::Formatter<'_>,
     cfg: &GridConfig,
@@ -968,15 +1144,24 @@ fn repeat_char(f: &mut fmt::Formatter<'_>, c: char, n: usize) -> fmt::Result {
 }
 
 // only valid to call for stabilized widths.
-fn total_width<R, W>(cfg: &GridConfig, records: &R, width: &W) -> usize
+fn total_width<R, W>(vertical_widths: &[usize], width: &W) -> usize
 where
     W: Estimate<R>,
-    R: Records,
 {
     let content_width = width.total();
-    let count_borders = cfg.count_vertical(records.count_columns());
+    let borders_width = vertical_widths.iter().sum::<usize>();
 
-    content_width + count_borders
+    content_width + borders_width
+}
+
+fn vertical_widths<R>(cfg: &GridConfig, records: &R) -> Vec<usize>
+where
+    R: Records,
+{
+    let count_columns = records.count_columns();
+    (0..=count_columns)
+        .map(|col| cfg.get_vertical_width(col, count_columns))
+        .collect()
 }
 
 fn total_height<R, H>(cfg: &GridConfig, records: &R, height: &H) -> usize
@@ -995,6 +1180,7 @@ fn print_vertical_char<R>(
     cfg: &GridConfig,
     records: &R,
     pos: Position,
+    vertical_widths: &[usize],
     line_index: usize,
     count_lines: usize,
 ) -> fmt::Result
@@ -1005,24 +1191,27 @@ where
     if let Some(c) = left {
         let c = if cfg.is_overridden_vertical(pos) {
             cfg.lookup_overridden_vertical(pos, line_index, count_lines)
-                .unwrap_or(*c)
+                .map(BorderSymbol::from_char)
+                .map_or(Cow::Borrowed(c), Cow::Owned)
         } else {
-            *c
+            Cow::Borrowed(c)
         };
 
+        let width = vertical_widths[pos.1];
+
         #[cfg(feature = "color")]
         {
             if let Some(clr) = get_vertical_color(cfg, records, pos) {
                 clr.fmt_prefix(f)?;
-                f.write_char(c)?;
+                print_border_symbol(f, &c, width, None)?;
                 clr.fmt_suffix(f)?;
             } else {
-                f.write_char(c)?;
+                print_border_symbol(f, &c, width, None)?;
             }
         }
 
         #[cfg(not(feature = "color"))]
-        f.write_char(c)?;
+        print_border_symbol(f, &c, width, None)?;
     }
 
     Ok(())
@@ -1229,8 +1418,8 @@ fn count_borders_in_range(
 ) -> usize {
     (start..end)
         .skip(1)
-        .filter(|&i| cfg.has_vertical(i, count_columns))
-        .count()
+        .map(|i| cfg.get_vertical_width(i, count_columns))
+        .sum()
 }
 
 fn grid_cell_height<R, H>(cfg: &GridConfig, records: &R, height: &H, pos: Position) -> usize
@@ -1287,21 +1476,21 @@ fn closest_visible_row(
     }
 }
 
-fn get_vertical<R>(cfg: &GridConfig, records: R, pos: Position) -> Option<&char>
+fn get_vertical<R>(cfg: &GridConfig, records: R, pos: Position) -> Option<&BorderSymbol>
 where
     R: Records,
 {
     cfg.get_vertical(pos, records.count_columns())
 }
 
-fn get_horizontal<R>(cfg: &GridConfig, records: R, pos: Position) -> Option<&char>
+fn get_horizontal<R>(cfg: &GridConfig, records: R, pos: Position) -> Option<&BorderSymbol>
 where
     R: Records,
 {
     cfg.get_horizontal(pos, records.count_rows())
 }
 
-fn get_intersection<R>(cfg: &GridConfig, records: R, pos: Position) -> Option<&char>
+fn get_intersection<R>(cfg: &GridConfig, records: R, pos: Position) -> Option<&BorderSymbol>
 where
     R: Records,
 {
@@ -74,6 +74,7 @@
 //! );
 //! ```
 
+#[cfg(feature = "color")]
 mod color;
 mod config;
 mod estimation;
@@ -84,9 +85,9 @@ pub mod util;
 
 pub use self::{
     config::{
-        AlignmentHorizontal, AlignmentVertical, Border, Borders, Entity, EntityIterator,
-        Formatting, GridConfig, HorizontalLine, Indent, Margin, Offset, Padding, Position, Sides,
-        VerticalLine,
+        AlignmentHorizontal, AlignmentVertical, Border, BorderSymbol, Borders, Entity,
+        EntityIterator, Formatting, GridConfig, HorizontalLine, Indent, Margin, Offset, Padding,
+        Position, Sides, VerticalLine,
     },
     estimation::{height, width, Estimate},
     grid::Grid,
@@ -85,7 +85,7 @@ where
 impl<T> From<Vec<Vec<T>>> for VecRecords<T> {
     fn from(records: Vec<Vec<T>>) -> Self {
         let count_rows = records.len();
-        let count_cols = records.get(0).map_or(0, Vec::len);
+        let count_cols = records.first().map_or(0, Vec::len);
         let size = (count_rows, count_cols);
 
         Self { records, size }
@@ -43,7 +43,7 @@ pub fn spplit_str_at(text: &str, at: usize) -> (Cow<'_, str>, Cow<'_, str>) {
         let mut buf = buf.to_owned();
         buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
 
-        return (Cow::Owned(buf), Cow::Borrowed(rest));
+        (Cow::Owned(buf), Cow::Borrowed(rest))
     }
 }
 
@@ -19,6 +19,22 @@ test_table!(
     "+---+---+"
 );
 
+test_table!(
+    span_with_multi_char_vertical,
+    grid(2, 3)
+        .change_cell((0, 0), "a long text")
+        .config(|cfg|{
+            cfg.set_borders(Borders {
+                vertical: Some(" | "),
+                ..Default::default()
+            });
+            cfg.set_column_span((0, 0), 2);
+        })
+        .build(),
+    "a long text | 0-2"
+    "1-0  | 1-1  | 1-2"
+);
+
 test_table!(
     miltiline_span,
     grid(2, 2)
@@ -308,7 +324,7 @@ test_table!(
     row_span_with_no_split_style,
     grid(2, 2)
         .config(|cfg| {
-            cfg.set_borders(Borders::default());
+            cfg.set_borders(Borders::<char>::default());
             cfg.set_column_span((0, 0), 2);
             cfg.set_alignment_horizontal(Entity::Cell(0, 0), AlignmentHorizontal::Center);
         })
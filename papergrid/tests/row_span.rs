@@ -492,7 +492,7 @@ test_table!(
     grid(2, 2)
         .change_cell((0, 0), "1\n2\n3")
         .config(|cfg|{
-            cfg.set_borders(Borders::default());
+            cfg.set_borders(Borders::<char>::default());
             cfg.set_row_span((0, 0), 2);
             cfg.set_alignment_vertical(Cell(0, 0), AlignmentVertical::Center);
         })
@@ -34,7 +34,7 @@ test_table!(
     remove_border_test,
     grid(2, 2)
         .config(|cfg| {
-            cfg.set_borders(Borders::default());
+            cfg.set_borders(Borders::<char>::default());
             cfg.set_border(
                 (0, 0),
                 Border {
@@ -55,7 +55,7 @@ test_table!(
     entity_row_overrides_column_intersection_0,
     grid(2, 2)
         .config(|cfg| {
-            cfg.set_borders(Borders::default());
+            cfg.set_borders(Borders::<char>::default());
             cfg.set_padding(
                 Entity::Column(0),
                 Padding {
@@ -79,7 +79,7 @@ test_table!(
     entity_row_overrides_column_intersection_1,
     grid(2, 2)
         .config(|cfg| {
-            cfg.set_borders(Borders::default());
+            cfg.set_borders(Borders::<char>::default());
             cfg.set_padding(
                 Entity::Column(0),
                 Padding {
@@ -109,7 +109,7 @@ test_table!(
     entity_column_overrides_row_intersection_0,
     grid(2, 2)
         .config(|cfg| {
-            cfg.set_borders(Borders::default());
+            cfg.set_borders(Borders::<char>::default());
             cfg.set_padding(
                 Entity::Row(0),
                 Padding {
@@ -126,7 +126,7 @@ test_table!(
     entity_column_overrides_row_intersection_1,
     grid(2, 2)
         .config(|cfg| {
-            cfg.set_borders(Borders::default());
+            cfg.set_borders(Borders::<char>::default());
             cfg.set_padding(
                 Entity::Row(0),
                 Padding {
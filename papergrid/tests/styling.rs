@@ -1,4 +1,4 @@
-use papergrid::{AlignmentHorizontal, Border, Borders, Entity, Indent, Padding};
+use papergrid::{AlignmentHorizontal, Border, Borders, Entity, Indent, Offset, Padding};
 
 #[cfg(feature = "color")]
 use std::convert::TryFrom;
@@ -257,7 +257,7 @@ test_table!(
     when_1_vertical_is_set_second_must_use_default_test,
     grid(2, 2)
         .config(|cfg| {
-            cfg.set_borders(Borders::default());
+            cfg.set_borders(Borders::<char>::default());
             cfg.set_border(
                 (1, 0),
                 Border {
@@ -271,6 +271,167 @@ test_table!(
     "1-0*1-1"
 );
 
+test_table!(
+    grid_2x2_multi_char_borders_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_borders(Borders {
+                top: Some("-"),
+                top_left: Some("+-"),
+                top_right: Some("-+"),
+                top_intersection: Some("-+-"),
+                bottom: Some("-"),
+                bottom_left: Some("+-"),
+                bottom_right: Some("-+"),
+                bottom_intersection: Some("-+-"),
+                horizontal: Some("-"),
+                horizontal_left: Some("+-"),
+                horizontal_right: Some("-+"),
+                vertical: Some(" | "),
+                vertical_left: Some("| "),
+                vertical_right: Some(" |"),
+                intersection: Some("-+-"),
+            })
+        })
+        .build(),
+    "+-----+-----+"
+    "| 0-0 | 0-1 |"
+    "+-----+-----+"
+    "| 1-0 | 1-1 |"
+    "+-----+-----+"
+);
+
+test_table!(
+    grid_2x2_wide_char_borders_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_borders(Borders {
+                horizontal: Some("══"),
+                vertical: Some("🦀"),
+                intersection: Some("╬"),
+                ..Default::default()
+            })
+        })
+        .build(),
+    "0-0🦀0-1"
+    "═══╬════"
+    "1-0🦀1-1"
+);
+
+test_table!(
+    grid_2x2_multi_char_cell_border_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_border(
+                (0, 1),
+                Border {
+                    left: Some(" # "),
+                    ..Default::default()
+                },
+            )
+        })
+        .build(),
+    "+----+----+"
+    "|0-0 # 0-1|"
+    "+----+----+"
+    "|1-0 | 1-1|"
+    "+----+----+"
+);
+
+test_table!(
+    grid_2x2_mixed_width_vertical_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_borders(Borders {
+                vertical: Some("│"),
+                horizontal: Some("─"),
+                intersection: Some("┼"),
+                ..Default::default()
+            });
+            cfg.set_border(
+                (1, 1),
+                Border {
+                    left: Some("🦀"),
+                    ..Default::default()
+                },
+            );
+            cfg.set_border(
+                (0, 1),
+                Border {
+                    left: Some(" ┃ "),
+                    ..Default::default()
+                },
+            );
+        })
+        .build(),
+    "0-0 ┃ 0-1"
+    "────┼────"
+    "1-0🦀 1-1"
+);
+
+test_table!(
+    grid_2x2_wide_char_horizontal_override_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_borders(Borders {
+                top: Some("🦀"),
+                top_left: Some("+"),
+                top_right: Some("+"),
+                top_intersection: Some("+"),
+                vertical: Some("|"),
+                vertical_left: Some("|"),
+                vertical_right: Some("|"),
+                ..Default::default()
+            });
+            cfg.override_horizontal_border((0, 0), 'a', Offset::Begin(1));
+        })
+        .build(),
+    "+ a +🦀 +"
+    "|0-0|0-1|"
+    "|1-0|1-1|"
+);
+
+test_table!(
+    grid_2x2_multi_char_horizontal_override_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_borders(Borders {
+                top: Some("~="),
+                top_left: Some("~="),
+                top_right: Some("="),
+                top_intersection: Some("+"),
+                ..Default::default()
+            });
+            cfg.override_horizontal_border((0, 0), '@', Offset::Begin(1));
+        })
+        .build(),
+    "~= @~+~=~="
+    "  0-0 0-1 "
+    "  1-0 1-1 "
+);
+
+test_table!(
+    grid_2x2_multi_char_split_line_text_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_borders(Borders {
+                horizontal: Some("-"),
+                horizontal_left: Some("+-"),
+                horizontal_right: Some("-+"),
+                vertical: Some(" | "),
+                vertical_left: Some("| "),
+                vertical_right: Some(" |"),
+                intersection: Some("-+-"),
+                ..Default::default()
+            });
+            cfg.override_split_line(1, "a long text", Offset::Begin(0));
+        })
+        .build(),
+    "| 0-0 | 0-1 |"
+    "a long text-+"
+    "| 1-0 | 1-1 |"
+);
+
 #[cfg(feature = "color")]
 test_table!(
     grid_2x2_ansi_border_test,
@@ -109,7 +109,7 @@
 //! )
 //! ```
 
-use std::{borrow::Cow, iter::FromIterator};
+use std::{borrow::Cow, fmt, iter::FromIterator};
 
 use papergrid::{
     records::{
@@ -121,7 +121,7 @@ use papergrid::{
     AlignmentHorizontal, Entity, Formatting, GridConfig, Indent, Padding,
 };
 
-use crate::{Style, Table};
+use crate::{Style, Table, Tabled};
 
 /// Builder creates a [`Table`] from dynamic data set.
 ///
@@ -166,6 +166,10 @@ pub struct Builder<'a> {
     different_column_sizes_used: bool,
     /// A content of cells which are created in case rows has different length.
     empty_cell_text: Option<String>,
+    /// Groups of columns which are displayed as a row above a columns row.
+    header_groups: Vec<(Cow<'static, str>, usize)>,
+    /// Settings of columns of a [`Tabled`] type a builder was created from.
+    column_settings: Option<ColumnSettings>,
 }
 
 impl<'a> Builder<'a> {
@@ -244,6 +248,7 @@ impl<'a> Builder<'a> {
     /// ```
     pub fn remove_columns(&mut self) -> &mut Self {
         self.columns = None;
+        self.header_groups.clear();
         let size = self.get_size();
         self.size = size;
 
@@ -308,9 +313,27 @@ impl<'a> Builder<'a> {
             self.fix_rows();
         }
 
-        let has_columns = self.columns.is_some();
-        let records = build_grid(self.records, self.columns, self.size);
-        build_table(records, has_columns)
+        let mut columns = self.columns;
+        if let Some(header) = columns.as_mut() {
+            if !self.header_groups.is_empty() {
+                let groups = build_groups_row(header, &self.header_groups);
+                self.records.insert(0, std::mem::replace(header, groups));
+            }
+        }
+
+        let has_columns = columns.is_some();
+        let records = build_grid(self.records, columns, self.size);
+        let mut table = build_table(records, has_columns);
+
+        if has_columns {
+            set_groups_spans(&mut table, &self.header_groups);
+        }
+
+        if let Some(settings) = self.column_settings {
+            (settings.0)(&mut table, 0);
+        }
+
+        table
     }
 
     /// Add an index to the [`Table`].
@@ -337,7 +360,11 @@ impl<'a> Builder<'a> {
     ///      +---+-------+"
     /// )
     /// ```
-    pub fn index(self) -> IndexBuilder<'a> {
+    pub fn index(mut self) -> IndexBuilder<'a> {
+        // columns are rearranged so the settings of a `Tabled` type are no longer relevant
+        self.header_groups.clear();
+        self.column_settings = None;
+
         IndexBuilder::new(self)
     }
 
@@ -365,11 +392,35 @@ impl<'a> Builder<'a> {
     /// )
     /// ```
     pub fn clean(&mut self) -> &mut Self {
+        let count_columns = self.size;
+
         self.clean_columns();
         self.clean_rows();
+
+        // columns are shifted so the settings of a `Tabled` type are no longer relevant
+        if self.size != count_columns {
+            self.header_groups.clear();
+            self.column_settings = None;
+        }
+
         self
     }
 
+    /// Sets groups of columns of a [`Tabled`] type,
+    /// see [`Tabled::header_groups`].
+    pub(crate) fn set_header_groups(&mut self, groups: Vec<(Cow<'static, str>, usize)>) {
+        self.header_groups = groups;
+    }
+
+    /// Sets settings of columns of a [`Tabled`] type,
+    /// see [`Tabled::column_settings`].
+    pub(crate) fn set_column_settings<T>(&mut self)
+    where
+        T: Tabled,
+    {
+        self.column_settings = Some(ColumnSettings(apply_column_settings::<T>));
+    }
+
     /// Creates a Builder from a built [`Records`]
     ///
     /// [`Records`]: papergrid::records::Records
@@ -901,6 +952,70 @@ where
     }
 }
 
+// A function which applies [`Tabled::column_settings`] to a table,
+// it's not bound to a lifetime of a table so a builder stays covariant.
+#[derive(Clone, Copy)]
+struct ColumnSettings(for<'a, 'b> fn(&'b mut Table<VecRecords<CellInfo<'a>>>, usize));
+
+impl fmt::Debug for ColumnSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColumnSettings").finish()
+    }
+}
+
+fn apply_column_settings<T>(table: &mut Table<VecRecords<CellInfo<'_>>>, offset: usize)
+where
+    T: Tabled,
+{
+    T::column_settings(table, offset)
+}
+
+// Builds a groups row out of a columns row,
+// a header of an ungrouped column is moved to a groups row, as it takes both rows.
+fn build_groups_row<'a>(
+    columns: &mut [CellInfo<'a>],
+    groups: &[(Cow<'static, str>, usize)],
+) -> Vec<CellInfo<'a>> {
+    let ctrl = CfgWidthFunction::new(4);
+    let mut row = vec![CellInfo::default(); columns.len()];
+
+    let mut col = 0;
+    for (name, span) in groups {
+        if name.is_empty() {
+            for i in col..(col + span).min(columns.len()) {
+                row[i] = std::mem::take(&mut columns[i]);
+            }
+        } else if col < row.len() {
+            let name: Cow<'a, str> = name.clone();
+            CellMut::set(&mut row[col], name, &ctrl);
+        }
+
+        col += span;
+    }
+
+    row
+}
+
+fn set_groups_spans<R>(table: &mut Table<R>, groups: &[(Cow<'static, str>, usize)])
+where
+    R: Records,
+{
+    let count_columns = table.get_records().count_columns();
+
+    let mut col = 0;
+    for (name, span) in groups {
+        if name.is_empty() {
+            for i in col..(col + span).min(count_columns) {
+                table.get_config_mut().set_row_span((0, i), 2);
+            }
+        } else if *span > 1 && col < count_columns {
+            table.get_config_mut().set_column_span((0, col), *span);
+        }
+
+        col += span;
+    }
+}
+
 /// Building [`Table`] from ordinary data.
 fn build_table<R>(records: R, with_header: bool) -> Table<R>
 where
@@ -944,7 +1059,7 @@ fn configure_grid(cfg: &mut GridConfig) {
         Entity::Global,
         Formatting {
             horizontal_trim: false,
-            allow_lines_alignement: false,
+            allow_lines_alignment: false,
             vertical_trim: false,
         },
     );
@@ -48,6 +48,8 @@
 //! assert_eq!(table, expected);
 //! ```
 
+use std::{collections::BTreeSet, fmt::Write};
+
 use papergrid::util::{cut_str_basic, string_width};
 
 use crate::Tabled;
@@ -88,22 +90,60 @@ impl ExpandedDisplay {
     where
         T: Tabled,
     {
-        let data = iter
-            .into_iter()
-            .map(|i| {
-                i.fields()
+        let mut buf = String::new();
+        let mut records = Vec::new();
+        let mut dynamic = Vec::new();
+        for row in iter {
+            let mut record = Vec::with_capacity(T::LENGTH);
+            row.visit_fields(&mut |field| {
+                buf.clear();
+                let _ = write!(buf, "{}", field);
+                record.push(buf.escape_debug().to_string());
+            });
+
+            let fields = row.dynamic_fields();
+            if !fields.is_empty() {
+                let fields = fields
                     .into_iter()
-                    .map(|s| s.escape_debug().to_string())
-                    .collect()
-            })
-            .collect();
-        let header = T::headers()
+                    .map(|(key, value)| (key.into_owned(), value.escape_debug().to_string()))
+                    .collect::<Vec<_>>();
+                dynamic.push((records.len(), fields));
+            }
+
+            records.push(record);
+        }
+
+        let mut header = T::headers()
             .into_iter()
             .map(|s| s.escape_debug().to_string())
-            .collect();
+            .collect::<Vec<_>>();
+
+        // dynamic fields are a sorted union of their headers, same as in a table
+        if !dynamic.is_empty() {
+            let keys = dynamic
+                .iter()
+                .flat_map(|(_, fields)| fields.iter().map(|(key, _)| key.clone()))
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            for record in &mut records {
+                record.resize(T::LENGTH + keys.len(), String::new());
+            }
+
+            for (i, fields) in dynamic {
+                for (key, value) in fields {
+                    if let Ok(col) = keys.binary_search(&key) {
+                        records[i][T::LENGTH + col] = value;
+                    }
+                }
+            }
+
+            header.extend(keys.iter().map(|key| key.escape_debug().to_string()));
+        }
 
         Self {
-            records: data,
+            records,
             fields: header,
         }
     }
@@ -105,7 +105,6 @@ impl Color {
     /// A color representation.
     /// 
     /// Notice that the colors are constants so you can't combine them.
-
     pub const BG_BLACK:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[40m"),  Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
@@ -226,7 +225,7 @@ where
     }
 }
 
-impl<'b, R> CellOption<R> for &'b Color
+impl<R> CellOption<R> for &Color
 where
     R: Records,
 {
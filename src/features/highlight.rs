@@ -170,13 +170,13 @@ fn set_border_colored(
         return;
     }
 
-    let color = border.clone().into();
+    let color: papergrid::Border<char> = border.clone().into();
     for &(row, col) in &sector {
         let border = build_cell_border(&sector, (row, col), &color);
         cfg.set_border((row, col), border);
     }
 
-    let color = border.clone().into();
+    let color: papergrid::Border<papergrid::AnsiColor<'static>> = border.clone().into();
     for &(row, col) in &sector {
         let border = build_cell_border(&sector, (row, col), &color);
         cfg.set_border_color((row, col), border);
@@ -1,4 +1,4 @@
-use papergrid::{records::Records, Entity};
+use papergrid::{records::Records, BorderSymbol, Entity};
 
 use crate::{CellOption, Table};
 
@@ -13,11 +13,11 @@ use crate::{CellOption, Table};
 /// ```
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct Border {
-    border: Option<papergrid::Border<char>>,
+    border: Option<papergrid::Border<BorderSymbol>>,
 }
 
 impl Border {
-    pub(crate) const fn new_raw(border: Option<papergrid::Border<char>>) -> Self {
+    pub(crate) const fn new_raw(border: Option<papergrid::Border<BorderSymbol>>) -> Self {
         Self { border }
     }
 }
@@ -58,59 +58,59 @@ impl Border {
         Self::full(c, c, c, c, c, c, c, c)
     }
 
-    /// Set a top border character.
-    pub fn top(self, c: char) -> Self {
+    /// Set a top border symbol.
+    pub fn top(self, c: impl Into<BorderSymbol>) -> Self {
         let mut b = self.border.unwrap_or_default();
-        b.top = Some(c);
+        b.top = Some(c.into());
         Self::from(b)
     }
 
-    /// Set a bottom border character.
-    pub fn bottom(self, c: char) -> Self {
+    /// Set a bottom border symbol.
+    pub fn bottom(self, c: impl Into<BorderSymbol>) -> Self {
         let mut b = self.border.unwrap_or_default();
-        b.bottom = Some(c);
+        b.bottom = Some(c.into());
         Self::from(b)
     }
 
-    /// Set a left border character.
-    pub fn left(self, c: char) -> Self {
+    /// Set a left border symbol.
+    pub fn left(self, c: impl Into<BorderSymbol>) -> Self {
         let mut b = self.border.unwrap_or_default();
-        b.left = Some(c);
+        b.left = Some(c.into());
         Self::from(b)
     }
 
-    /// Set a right border character.
-    pub fn right(self, c: char) -> Self {
+    /// Set a right border symbol.
+    pub fn right(self, c: impl Into<BorderSymbol>) -> Self {
         let mut b = self.border.unwrap_or_default();
-        b.right = Some(c);
+        b.right = Some(c.into());
         Self::from(b)
     }
 
-    /// Set a top left intersection character.
-    pub fn top_left_corner(self, c: char) -> Self {
+    /// Set a top left intersection symbol.
+    pub fn top_left_corner(self, c: impl Into<BorderSymbol>) -> Self {
         let mut b = self.border.unwrap_or_default();
-        b.left_top_corner = Some(c);
+        b.left_top_corner = Some(c.into());
         Self::from(b)
     }
 
-    /// Set a top right intersection character.
-    pub fn top_right_corner(self, c: char) -> Self {
+    /// Set a top right intersection symbol.
+    pub fn top_right_corner(self, c: impl Into<BorderSymbol>) -> Self {
         let mut b = self.border.unwrap_or_default();
-        b.right_top_corner = Some(c);
+        b.right_top_corner = Some(c.into());
         Self::from(b)
     }
 
-    /// Set a bottom left intersection character.
-    pub fn bottom_left_corner(self, c: char) -> Self {
+    /// Set a bottom left intersection symbol.
+    pub fn bottom_left_corner(self, c: impl Into<BorderSymbol>) -> Self {
         let mut b = self.border.unwrap_or_default();
-        b.left_bottom_corner = Some(c);
+        b.left_bottom_corner = Some(c.into());
         Self::from(b)
     }
 
-    /// Set a bottom right intersection character.
-    pub fn bottom_right_corner(self, c: char) -> Self {
+    /// Set a bottom right intersection symbol.
+    pub fn bottom_right_corner(self, c: impl Into<BorderSymbol>) -> Self {
         let mut b = self.border.unwrap_or_default();
-        b.right_bottom_corner = Some(c);
+        b.right_bottom_corner = Some(c.into());
         Self::from(b)
     }
 }
@@ -136,11 +136,19 @@ where
 
 impl From<papergrid::Border> for Border {
     fn from(b: papergrid::Border) -> Border {
+        Border {
+            border: Some(b.map(BorderSymbol::from_char)),
+        }
+    }
+}
+
+impl From<papergrid::Border<BorderSymbol>> for Border {
+    fn from(b: papergrid::Border<BorderSymbol>) -> Border {
         Border { border: Some(b) }
     }
 }
 
-impl From<Border> for Option<papergrid::Border> {
+impl From<Border> for Option<papergrid::Border<BorderSymbol>> {
     fn from(val: Border) -> Self {
         val.border
     }
@@ -1,6 +1,6 @@
 use crate::{Table, TableOption};
 
-use super::Line;
+use super::{line::symbol, Line};
 
 /// A horizontal split line which can be used to set a border.
 #[derive(Debug, Clone)]
@@ -26,52 +26,52 @@ impl HorizontalLine {
     }
 
     /// Sets a horizontal character.
-    pub const fn main(mut self, c: Option<char>) -> Self {
+    pub fn main(mut self, c: Option<char>) -> Self {
         let mut line = match self.line {
             Some(line) => line,
             None => Line::empty(),
         };
 
-        line.main = c;
+        line.main = symbol(c);
         self.line = Some(line);
 
         self
     }
 
     /// Sets a vertical intersection character.
-    pub const fn intersection(mut self, c: Option<char>) -> Self {
+    pub fn intersection(mut self, c: Option<char>) -> Self {
         let mut line = match self.line {
             Some(line) => line,
             None => Line::empty(),
         };
 
-        line.intersection = c;
+        line.intersection = symbol(c);
         self.line = Some(line);
 
         self
     }
 
     /// Sets a left character.
-    pub const fn left(mut self, c: Option<char>) -> Self {
+    pub fn left(mut self, c: Option<char>) -> Self {
         let mut line = match self.line {
             Some(line) => line,
             None => Line::empty(),
         };
 
-        line.connector1 = c;
+        line.connector1 = symbol(c);
         self.line = Some(line);
 
         self
     }
 
     /// Sets a right character.
-    pub const fn right(mut self, c: Option<char>) -> Self {
+    pub fn right(mut self, c: Option<char>) -> Self {
         let mut line = match self.line {
             Some(line) => line,
             None => Line::empty(),
         };
 
-        line.connector2 = c;
+        line.connector2 = symbol(c);
         self.line = Some(line);
 
         self
@@ -91,7 +91,7 @@ impl<R> TableOption<R> for HorizontalLine {
         match &self.line {
             Some(line) => table
                 .get_config_mut()
-                .set_horizontal_line(self.index, papergrid::HorizontalLine::from(*line)),
+                .set_horizontal_line(self.index, papergrid::HorizontalLine::from(line.clone())),
             None => table.get_config_mut().remove_horizontal_line(self.index),
         }
     }
@@ -1,10 +1,12 @@
+use papergrid::BorderSymbol;
+
 /// The structure represent a vertical or horizontal line.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
 pub struct Line {
-    pub(crate) main: Option<char>,
-    pub(crate) intersection: Option<char>,
-    pub(crate) connector1: Option<char>,
-    pub(crate) connector2: Option<char>,
+    pub(crate) main: Option<BorderSymbol>,
+    pub(crate) intersection: Option<BorderSymbol>,
+    pub(crate) connector1: Option<BorderSymbol>,
+    pub(crate) connector2: Option<BorderSymbol>,
 }
 
 impl Line {
@@ -16,10 +18,10 @@ impl Line {
         connector2: Option<char>,
     ) -> Self {
         Self {
-            main,
-            intersection,
-            connector1,
-            connector2,
+            main: symbol(main),
+            intersection: symbol(intersection),
+            connector1: symbol(connector1),
+            connector2: symbol(connector2),
         }
     }
 
@@ -38,6 +40,19 @@ impl Line {
         Self::full(c, c, c, c)
     }
 
+    /// Creates a new [`Line`] object with all symbols set to the provided one.
+    ///
+    /// Unlike [`Line::filled`] the symbol may be a string of any width.
+    pub fn filled_with(s: impl Into<BorderSymbol>) -> Self {
+        let s = Some(s.into());
+        Self {
+            main: s.clone(),
+            intersection: s.clone(),
+            connector1: s.clone(),
+            connector2: s,
+        }
+    }
+
     /// Creates a new [`Line`] object with all chars not set.
     pub const fn empty() -> Self {
         Self::new(None, None, None, None)
@@ -52,7 +67,7 @@ impl Line {
     }
 }
 
-impl From<Line> for papergrid::HorizontalLine<char> {
+impl From<Line> for papergrid::HorizontalLine<BorderSymbol> {
     fn from(l: Line) -> Self {
         Self {
             main: l.main,
@@ -63,7 +78,7 @@ impl From<Line> for papergrid::HorizontalLine<char> {
     }
 }
 
-impl From<Line> for papergrid::VerticalLine<char> {
+impl From<Line> for papergrid::VerticalLine<BorderSymbol> {
     fn from(l: Line) -> Self {
         Self {
             main: l.main,
@@ -73,3 +88,10 @@ impl From<Line> for papergrid::VerticalLine<char> {
         }
     }
 }
+
+pub(crate) const fn symbol(c: Option<char>) -> Option<BorderSymbol> {
+    match c {
+        Some(c) => Some(BorderSymbol::from_char(c)),
+        None => None,
+    }
+}
@@ -27,6 +27,8 @@ pub use self::{
     span_border_correction::StyleCorrectSpan, style::Style, vertical_line::VerticalLine,
 };
 
+pub use papergrid::BorderSymbol;
+
 #[cfg(feature = "color")]
 #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
 pub use self::{border_colored::BorderColored, raw_style_colored::RawStyleColored, symbol::Symbol};
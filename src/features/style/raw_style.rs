@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-use papergrid::{records::Records, Borders};
+use papergrid::{records::Records, BorderSymbol, Borders};
 
 use crate::{
     style::{HorizontalLine, Line, VerticalLine},
@@ -15,7 +15,7 @@ use crate::{
 /// It can be useful in order to not have a generics and be able to use it as a variable more conveniently.
 #[derive(Default, Debug, Clone)]
 pub struct RawStyle {
-    borders: Borders<char>,
+    borders: Borders<BorderSymbol>,
     horizontals: HashMap<usize, Line>,
     verticals: HashMap<usize, Line>,
 }
@@ -23,91 +23,91 @@ pub struct RawStyle {
 impl RawStyle {
     /// Set a top border character.
     pub fn set_top(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.top = s;
+        self.borders.top = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a bottom border character.
     pub fn set_bottom(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.bottom = s;
+        self.borders.bottom = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a left border character.
     pub fn set_left(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.vertical_left = s;
+        self.borders.vertical_left = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a right border character.
     pub fn set_right(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.vertical_right = s;
+        self.borders.vertical_right = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a top split border character.
     pub fn set_top_split(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.top_intersection = s;
+        self.borders.top_intersection = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a bottom split character.
     pub fn set_bottom_split(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.bottom_intersection = s;
+        self.borders.bottom_intersection = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a left split character.
     pub fn set_left_split(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.horizontal_left = s;
+        self.borders.horizontal_left = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a right split character.
     pub fn set_right_split(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.horizontal_right = s;
+        self.borders.horizontal_right = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set an internal character.
     pub fn set_internal_split(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.intersection = s;
+        self.borders.intersection = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a vertical character.
     pub fn set_vertical(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.vertical = s;
+        self.borders.vertical = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a horizontal character.
     pub fn set_horizontal(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.horizontal = s;
+        self.borders.horizontal = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a character for a top left corner.
     pub fn set_top_left(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.top_left = s;
+        self.borders.top_left = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a character for a top right corner.
     pub fn set_top_right(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.top_right = s;
+        self.borders.top_right = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a character for a bottom left corner.
     pub fn set_bottom_left(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.bottom_left = s;
+        self.borders.bottom_left = s.map(BorderSymbol::from_char);
         self
     }
 
     /// Set a character for a bottom right corner.
     pub fn set_bottom_right(&mut self, s: Option<char>) -> &mut Self {
-        self.borders.bottom_right = s;
+        self.borders.bottom_right = s.map(BorderSymbol::from_char);
         self
     }
 
@@ -187,77 +187,77 @@ impl RawStyle {
         self
     }
 
-    /// Get a left char.
-    pub fn get_left(&self) -> Option<char> {
-        self.borders.vertical_left
+    /// Get a left symbol.
+    pub fn get_left(&self) -> Option<BorderSymbol> {
+        self.borders.vertical_left.clone()
     }
 
-    /// Get a left intersection char.
-    pub fn get_left_intersection(&self) -> Option<char> {
-        self.borders.horizontal_left
+    /// Get a left intersection symbol.
+    pub fn get_left_intersection(&self) -> Option<BorderSymbol> {
+        self.borders.horizontal_left.clone()
     }
 
-    /// Get a right char.
-    pub fn get_right(&self) -> Option<char> {
-        self.borders.vertical_right
+    /// Get a right symbol.
+    pub fn get_right(&self) -> Option<BorderSymbol> {
+        self.borders.vertical_right.clone()
     }
 
-    /// Get a right intersection char.
-    pub fn get_right_intersection(&self) -> Option<char> {
-        self.borders.horizontal_right
+    /// Get a right intersection symbol.
+    pub fn get_right_intersection(&self) -> Option<BorderSymbol> {
+        self.borders.horizontal_right.clone()
     }
 
-    /// Get a top char.
-    pub fn get_top(&self) -> Option<char> {
-        self.borders.top
+    /// Get a top symbol.
+    pub fn get_top(&self) -> Option<BorderSymbol> {
+        self.borders.top.clone()
     }
 
-    /// Get a top left char.
-    pub fn get_top_left(&self) -> Option<char> {
-        self.borders.top_left
+    /// Get a top left symbol.
+    pub fn get_top_left(&self) -> Option<BorderSymbol> {
+        self.borders.top_left.clone()
     }
 
-    /// Get a top right char.
-    pub fn get_top_right(&self) -> Option<char> {
-        self.borders.top_right
+    /// Get a top right symbol.
+    pub fn get_top_right(&self) -> Option<BorderSymbol> {
+        self.borders.top_right.clone()
     }
 
-    /// Get a top intersection char.
-    pub fn get_top_intersection(&self) -> Option<char> {
-        self.borders.top_intersection
+    /// Get a top intersection symbol.
+    pub fn get_top_intersection(&self) -> Option<BorderSymbol> {
+        self.borders.top_intersection.clone()
     }
 
-    /// Get a bottom intersection char.
-    pub fn get_bottom(&self) -> Option<char> {
-        self.borders.bottom
+    /// Get a bottom intersection symbol.
+    pub fn get_bottom(&self) -> Option<BorderSymbol> {
+        self.borders.bottom.clone()
     }
 
-    /// Get a bottom intersection char.
-    pub fn get_bottom_left(&self) -> Option<char> {
-        self.borders.bottom_left
+    /// Get a bottom intersection symbol.
+    pub fn get_bottom_left(&self) -> Option<BorderSymbol> {
+        self.borders.bottom_left.clone()
     }
 
-    /// Get a bottom intersection char.
-    pub fn get_bottom_right(&self) -> Option<char> {
-        self.borders.bottom_right
+    /// Get a bottom intersection symbol.
+    pub fn get_bottom_right(&self) -> Option<BorderSymbol> {
+        self.borders.bottom_right.clone()
     }
 
-    /// Get a bottom intersection char.
-    pub fn get_bottom_intersection(&self) -> Option<char> {
-        self.borders.bottom_intersection
+    /// Get a bottom intersection symbol.
+    pub fn get_bottom_intersection(&self) -> Option<BorderSymbol> {
+        self.borders.bottom_intersection.clone()
     }
 
     /// Returns an outer border of the style.
     pub fn get_frame(&self) -> Border {
         Border::new_raw(Some(papergrid::Border {
-            top: self.borders.top,
-            bottom: self.borders.bottom,
-            left: self.borders.vertical_left,
-            right: self.borders.vertical_right,
-            left_top_corner: self.borders.top_left,
-            right_top_corner: self.borders.top_right,
-            left_bottom_corner: self.borders.bottom_left,
-            right_bottom_corner: self.borders.bottom_right,
+            top: self.borders.top.clone(),
+            bottom: self.borders.bottom.clone(),
+            left: self.borders.vertical_left.clone(),
+            right: self.borders.vertical_right.clone(),
+            left_top_corner: self.borders.top_left.clone(),
+            right_top_corner: self.borders.top_right.clone(),
+            left_bottom_corner: self.borders.bottom_left.clone(),
+            right_bottom_corner: self.borders.bottom_right.clone(),
         }))
     }
 
@@ -271,6 +271,12 @@ impl RawStyle {
 
 impl From<Borders<char>> for RawStyle {
     fn from(borders: Borders<char>) -> Self {
+        Self::from(borders.map(BorderSymbol::from_char))
+    }
+}
+
+impl From<Borders<BorderSymbol>> for RawStyle {
+    fn from(borders: Borders<BorderSymbol>) -> Self {
         Self {
             borders,
             horizontals: HashMap::new(),
@@ -308,7 +314,7 @@ where
                 if line.is_empty() {
                     cfg.remove_horizontal_line(row);
                 } else {
-                    cfg.set_horizontal_line(row, papergrid::HorizontalLine::from(*line));
+                    cfg.set_horizontal_line(row, papergrid::HorizontalLine::from(line.clone()));
                 }
             }
         }
@@ -318,7 +324,7 @@ where
                 if line.is_empty() {
                     cfg.remove_vertical_line(col);
                 } else {
-                    cfg.set_vertical_line(col, papergrid::VerticalLine::from(*line));
+                    cfg.set_vertical_line(col, papergrid::VerticalLine::from(line.clone()));
                 }
             }
         }
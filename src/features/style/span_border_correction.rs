@@ -50,26 +50,26 @@ where
             let has_top_border = border.left_top_corner.is_some() && border.top.is_some();
             if has_top_border {
                 if has_up && is_first {
-                    border.left_top_corner = borders.intersection;
+                    border.left_top_corner = borders.intersection.clone();
                 } else if has_up {
-                    border.left_top_corner = borders.bottom_intersection;
+                    border.left_top_corner = borders.bottom_intersection.clone();
                 } else if is_first {
-                    border.left_top_corner = borders.top_intersection;
+                    border.left_top_corner = borders.top_intersection.clone();
                 } else {
-                    border.left_top_corner = border.top;
+                    border.left_top_corner = border.top.clone();
                 }
             }
 
             let has_bottom_border = border.left_bottom_corner.is_some() && border.bottom.is_some();
             if has_bottom_border {
                 if has_down && is_first {
-                    border.left_bottom_corner = borders.intersection;
+                    border.left_bottom_corner = borders.intersection.clone();
                 } else if has_down {
-                    border.left_bottom_corner = borders.top_intersection;
+                    border.left_bottom_corner = borders.top_intersection.clone();
                 } else if is_first {
-                    border.left_bottom_corner = borders.bottom_intersection;
+                    border.left_bottom_corner = borders.bottom_intersection.clone();
                 } else {
-                    border.left_bottom_corner = border.bottom;
+                    border.left_bottom_corner = border.bottom.clone();
                 }
             }
 
@@ -90,9 +90,9 @@ where
             if has_left_border {
                 let has_left = col > 0 && has_top(table, (row, col - 1));
                 if has_left {
-                    border.left_top_corner = borders.horizontal_right;
+                    border.left_top_corner = borders.horizontal_right.clone();
                 } else {
-                    border.left_top_corner = borders.vertical;
+                    border.left_top_corner = borders.vertical.clone();
                 }
             }
 
@@ -100,9 +100,9 @@ where
             if has_right_border {
                 let has_right = col + 1 < table.shape().1 && has_top(table, (row, col + 1));
                 if has_right {
-                    border.right_top_corner = borders.horizontal_left;
+                    border.right_top_corner = borders.horizontal_left.clone();
                 } else {
-                    border.right_top_corner = borders.vertical;
+                    border.right_top_corner = borders.vertical.clone();
                 }
             }
 
@@ -122,12 +122,12 @@ where
         let has_right = col + 1 < table.shape().1 && has_top(table, (row, col + 1));
         let has_up = has_left(table, (row - 1, col));
         if has_up && !has_right {
-            border.right_top_corner = borders.horizontal_right;
+            border.right_top_corner = borders.horizontal_right.clone();
         }
 
         let has_down = row + 1 < table.shape().0 && has_left(table, (row + 1, col));
         if has_down {
-            border.left_bottom_corner = borders.top_intersection;
+            border.left_bottom_corner = borders.top_intersection.clone();
         }
 
         table.get_config_mut().set_border((row, col), border);
@@ -93,11 +93,11 @@
 
 use std::marker::PhantomData;
 
-use papergrid::{records::Records, Borders};
+use papergrid::{records::Records, BorderSymbol, Borders};
 
 use crate::{style::StyleCorrectSpan, Border, Table, TableOption};
 
-use super::{HorizontalLine, Line, VerticalLine};
+use super::{line::symbol, HorizontalLine, Line, VerticalLine};
 
 /// Style is represents a theme of a [`Table`].
 ///
@@ -125,7 +125,7 @@ use super::{HorizontalLine, Line, VerticalLine};
 /// [`RawStyle`]: crate::style::RawStyle
 #[derive(Debug, Clone)]
 pub struct Style<T, B, L, R, H, V, HLines = HLineArray<0>, VLines = VLineArray<0>> {
-    pub(crate) borders: Borders<char>,
+    pub(crate) borders: Borders<BorderSymbol>,
     pub(crate) horizontals: HLines,
     pub(crate) verticals: VLines,
     _top: PhantomData<T>,
@@ -170,9 +170,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn empty() -> Style<(), (), (), (), (), ()> {
         Style::new(
             create_borders(
-                Line::empty(),
-                Line::empty(),
-                Line::empty(),
+                LineChars::empty(),
+                LineChars::empty(),
+                LineChars::empty(),
                 None,
                 None,
                 None,
@@ -193,9 +193,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn blank() -> Style<(), (), (), (), (), On> {
         Style::new(
             create_borders(
-                Line::empty(),
-                Line::empty(),
-                Line::empty(),
+                LineChars::empty(),
+                LineChars::empty(),
+                LineChars::empty(),
                 None,
                 None,
                 Some(' '),
@@ -223,9 +223,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn ascii() -> Style<On, On, On, On, On, On> {
         Style::new(
             create_borders(
-                Line::full('-', '+', '+', '+'),
-                Line::full('-', '+', '+', '+'),
-                Line::full('-', '+', '+', '+'),
+                LineChars::full('-', '+', '+', '+'),
+                LineChars::full('-', '+', '+', '+'),
+                LineChars::full('-', '+', '+', '+'),
                 Some('|'),
                 Some('|'),
                 Some('|'),
@@ -250,16 +250,17 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn psql() -> Style<(), (), (), (), (), On, HLineArray<1>> {
         Style::new(
             create_borders(
-                Line::empty(),
-                Line::empty(),
-                Line::empty(),
+                LineChars::empty(),
+                LineChars::empty(),
+                LineChars::empty(),
                 None,
                 None,
                 Some('|'),
             ),
-            [HorizontalLine::new(1, Line::empty())
-                .main(Some('-'))
-                .intersection(Some('+'))],
+            [HorizontalLine::new(
+                1,
+                Line::new(Some('-'), Some('+'), None, None),
+            )],
             [],
         )
     }
@@ -276,9 +277,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn markdown() -> Style<(), (), On, On, (), On, HLineArray<1>> {
         Style::new(
             create_borders(
-                Line::empty(),
-                Line::empty(),
-                Line::empty(),
+                LineChars::empty(),
+                LineChars::empty(),
+                LineChars::empty(),
                 Some('|'),
                 Some('|'),
                 Some('|'),
@@ -306,9 +307,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn modern() -> Style<On, On, On, On, On, On> {
         Style::new(
             create_borders(
-                Line::full('─', '┬', '┌', '┐'),
-                Line::full('─', '┴', '└', '┘'),
-                Line::full('─', '┼', '├', '┤'),
+                LineChars::full('─', '┬', '┌', '┐'),
+                LineChars::full('─', '┴', '└', '┘'),
+                LineChars::full('─', '┼', '├', '┤'),
                 Some('│'),
                 Some('│'),
                 Some('│'),
@@ -334,9 +335,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn sharp() -> Style<On, On, On, On, (), On, HLineArray<1>> {
         Style::new(
             create_borders(
-                Line::full('─', '┬', '┌', '┐'),
-                Line::full('─', '┴', '└', '┘'),
-                Line::empty(),
+                LineChars::full('─', '┬', '┌', '┐'),
+                LineChars::full('─', '┴', '└', '┘'),
+                LineChars::empty(),
                 Some('│'),
                 Some('│'),
                 Some('│'),
@@ -362,9 +363,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn rounded() -> Style<On, On, On, On, (), On, HLineArray<1>> {
         Style::new(
             create_borders(
-                Line::full('─', '┬', '╭', '╮'),
-                Line::full('─', '┴', '╰', '╯'),
-                Line::empty(),
+                LineChars::full('─', '┬', '╭', '╮'),
+                LineChars::full('─', '┴', '╰', '╯'),
+                LineChars::empty(),
                 Some('│'),
                 Some('│'),
                 Some('│'),
@@ -392,9 +393,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn extended() -> Style<On, On, On, On, On, On> {
         Style::new(
             create_borders(
-                Line::full('═', '╦', '╔', '╗'),
-                Line::full('═', '╩', '╚', '╝'),
-                Line::full('═', '╬', '╠', '╣'),
+                LineChars::full('═', '╦', '╔', '╗'),
+                LineChars::full('═', '╩', '╚', '╝'),
+                LineChars::full('═', '╬', '╠', '╣'),
                 Some('║'),
                 Some('║'),
                 Some('║'),
@@ -421,9 +422,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn dots() -> Style<On, On, On, On, On, On> {
         Style::new(
             create_borders(
-                Line::full('.', '.', '.', '.'),
-                Line::full('.', ':', ':', ':'),
-                Line::full('.', ':', ':', ':'),
+                LineChars::full('.', '.', '.', '.'),
+                LineChars::full('.', ':', ':', ':'),
+                LineChars::full('.', ':', ':', ':'),
                 Some(':'),
                 Some(':'),
                 Some(':'),
@@ -447,9 +448,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn re_structured_text() -> Style<On, On, (), (), (), On, HLineArray<1>> {
         Style::new(
             create_borders(
-                Line::new(Some('='), Some(' '), None, None),
-                Line::new(Some('='), Some(' '), None, None),
-                Line::empty(),
+                LineChars::new(Some('='), Some(' '), None, None),
+                LineChars::new(Some('='), Some(' '), None, None),
+                LineChars::empty(),
                 None,
                 None,
                 Some(' '),
@@ -476,9 +477,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
     pub const fn ascii_rounded() -> Style<On, On, On, On, (), On> {
         Style::new(
             create_borders(
-                Line::full('-', '-', '.', '.'),
-                Line::full('-', '-', '\'', '\''),
-                Line::empty(),
+                LineChars::full('-', '-', '.', '.'),
+                LineChars::full('-', '-', '\'', '\''),
+                LineChars::empty(),
                 Some('|'),
                 Some('|'),
                 Some('|'),
@@ -573,16 +574,16 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
     ///     )
     /// );
     /// ```
-    pub const fn get_frame(&self) -> Border {
+    pub fn get_frame(&self) -> Border {
         Border::new_raw(Some(papergrid::Border {
-            top: self.borders.top,
-            bottom: self.borders.bottom,
-            left: self.borders.vertical_left,
-            right: self.borders.vertical_right,
-            left_top_corner: self.borders.top_left,
-            right_top_corner: self.borders.top_right,
-            left_bottom_corner: self.borders.bottom_left,
-            right_bottom_corner: self.borders.bottom_right,
+            top: self.borders.top.clone(),
+            bottom: self.borders.bottom.clone(),
+            left: self.borders.vertical_left.clone(),
+            right: self.borders.vertical_right.clone(),
+            left_top_corner: self.borders.top_left.clone(),
+            right_top_corner: self.borders.top_right.clone(),
+            left_bottom_corner: self.borders.bottom_left.clone(),
+            right_bottom_corner: self.borders.bottom_right.clone(),
         }))
     }
 
@@ -614,13 +615,13 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
     ///     )
     /// )
     /// ```
-    pub const fn get_horizontal(&self) -> Line {
-        Line::new(
-            self.borders.horizontal,
-            self.borders.intersection,
-            self.borders.horizontal_left,
-            self.borders.horizontal_right,
-        )
+    pub fn get_horizontal(&self) -> Line {
+        Line {
+            main: self.borders.horizontal.clone(),
+            intersection: self.borders.intersection.clone(),
+            connector1: self.borders.horizontal_left.clone(),
+            connector2: self.borders.horizontal_right.clone(),
+        }
     }
 
     /// Get a [`Style`]'s default horizontal line.
@@ -650,39 +651,41 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
     ///     )
     /// )
     /// ```
-    pub const fn get_vertical(&self) -> Line {
-        Line::new(
-            self.borders.vertical,
-            self.borders.intersection,
-            self.borders.top_intersection,
-            self.borders.bottom_intersection,
-        )
+    pub fn get_vertical(&self) -> Line {
+        Line {
+            main: self.borders.vertical.clone(),
+            intersection: self.borders.intersection.clone(),
+            connector1: self.borders.top_intersection.clone(),
+            connector2: self.borders.bottom_intersection.clone(),
+        }
     }
 
     /// Sets a top border.
     ///
     /// Any corners and intersections which were set will be overridden.
-    pub fn top(mut self, c: char) -> Style<On, B, L, R, H, V, HLines, VLines>
+    pub fn top(mut self, c: impl Into<BorderSymbol>) -> Style<On, B, L, R, H, V, HLines, VLines>
     where
         for<'a> &'a mut VLines: IntoIterator<Item = &'a mut VerticalLine>,
     {
-        self.borders.top = Some(c);
+        let c = c.into();
+
+        self.borders.top = Some(c.clone());
 
         if self.borders.has_left() {
-            self.borders.top_left = Some(c);
+            self.borders.top_left = Some(c.clone());
         }
 
         if self.borders.has_right() {
-            self.borders.top_right = Some(c);
+            self.borders.top_right = Some(c.clone());
         }
 
         if self.borders.has_vertical() {
-            self.borders.top_intersection = Some(c);
+            self.borders.top_intersection = Some(c.clone());
         }
 
         for vl in &mut self.verticals {
             if let Some(line) = &mut vl.line {
-                line.connector1 = Some(c);
+                line.connector1 = Some(c.clone());
             }
         }
 
@@ -692,27 +695,29 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
     /// Sets a bottom border.
     ///
     /// Any corners and intersections which were set will be overridden.
-    pub fn bottom(mut self, c: char) -> Style<T, On, L, R, H, V, HLines, VLines>
+    pub fn bottom(mut self, c: impl Into<BorderSymbol>) -> Style<T, On, L, R, H, V, HLines, VLines>
     where
         for<'a> &'a mut VLines: IntoIterator<Item = &'a mut VerticalLine>,
     {
-        self.borders.bottom = Some(c);
+        let c = c.into();
+
+        self.borders.bottom = Some(c.clone());
 
         if self.borders.has_left() {
-            self.borders.bottom_left = Some(c);
+            self.borders.bottom_left = Some(c.clone());
         }
 
         if self.borders.has_right() {
-            self.borders.bottom_right = Some(c);
+            self.borders.bottom_right = Some(c.clone());
         }
 
         if self.borders.has_vertical() {
-            self.borders.bottom_intersection = Some(c);
+            self.borders.bottom_intersection = Some(c.clone());
         }
 
         for vl in &mut self.verticals {
             if let Some(line) = &mut vl.line {
-                line.connector2 = Some(c);
+                line.connector2 = Some(c.clone());
             }
         }
 
@@ -722,27 +727,29 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
     /// Sets a left border.
     ///
     /// Any corners and intersections which were set will be overridden.
-    pub fn left(mut self, c: char) -> Style<T, B, On, R, H, V, HLines, VLines>
+    pub fn left(mut self, c: impl Into<BorderSymbol>) -> Style<T, B, On, R, H, V, HLines, VLines>
     where
         for<'a> &'a mut HLines: IntoIterator<Item = &'a mut HorizontalLine>,
     {
-        self.borders.vertical_left = Some(c);
+        let c = c.into();
+
+        self.borders.vertical_left = Some(c.clone());
 
         if self.borders.has_top() {
-            self.borders.top_left = Some(c);
+            self.borders.top_left = Some(c.clone());
         }
 
         if self.borders.has_bottom() {
-            self.borders.bottom_left = Some(c);
+            self.borders.bottom_left = Some(c.clone());
         }
 
         if self.borders.has_horizontal() {
-            self.borders.horizontal_left = Some(c);
+            self.borders.horizontal_left = Some(c.clone());
         }
 
         for hl in &mut self.horizontals {
             if let Some(line) = &mut hl.line {
-                line.connector1 = Some(c);
+                line.connector1 = Some(c.clone());
             }
         }
 
@@ -752,27 +759,29 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
     /// Sets a right border.
     ///
     /// Any corners and intersections which were set will be overridden.
-    pub fn right(mut self, c: char) -> Style<T, B, L, On, H, V, HLines, VLines>
+    pub fn right(mut self, c: impl Into<BorderSymbol>) -> Style<T, B, L, On, H, V, HLines, VLines>
     where
         for<'a> &'a mut HLines: IntoIterator<Item = &'a mut HorizontalLine>,
     {
-        self.borders.vertical_right = Some(c);
+        let c = c.into();
+
+        self.borders.vertical_right = Some(c.clone());
 
         if self.borders.has_top() {
-            self.borders.top_right = Some(c);
+            self.borders.top_right = Some(c.clone());
         }
 
         if self.borders.has_bottom() {
-            self.borders.bottom_right = Some(c);
+            self.borders.bottom_right = Some(c.clone());
         }
 
         if self.borders.has_horizontal() {
-            self.borders.horizontal_right = Some(c);
+            self.borders.horizontal_right = Some(c.clone());
         }
 
         for hl in &mut self.horizontals {
             if let Some(line) = &mut hl.line {
-                line.connector2 = Some(c);
+                line.connector2 = Some(c.clone());
             }
         }
 
@@ -782,27 +791,32 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
     /// Sets a horizontal split line.
     ///
     /// Any corners and intersections which were set will be overridden.
-    pub fn horizontal(mut self, c: char) -> Style<T, B, L, R, On, V, HLines, VLines>
+    pub fn horizontal(
+        mut self,
+        c: impl Into<BorderSymbol>,
+    ) -> Style<T, B, L, R, On, V, HLines, VLines>
     where
         for<'a> &'a mut VLines: IntoIterator<Item = &'a mut VerticalLine>,
     {
-        self.borders.horizontal = Some(c);
+        let c = c.into();
+
+        self.borders.horizontal = Some(c.clone());
 
         if self.borders.has_vertical() {
-            self.borders.intersection = Some(c);
+            self.borders.intersection = Some(c.clone());
         }
 
         if self.borders.has_left() {
-            self.borders.horizontal_left = Some(c);
+            self.borders.horizontal_left = Some(c.clone());
         }
 
         if self.borders.has_right() {
-            self.borders.horizontal_right = Some(c);
+            self.borders.horizontal_right = Some(c.clone());
         }
 
         for vl in &mut self.verticals {
             if let Some(line) = &mut vl.line {
-                line.intersection = Some(c);
+                line.intersection = Some(c.clone());
             }
         }
 
@@ -812,27 +826,32 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
     /// Sets a vertical split line.
     ///
     /// Any corners and intersections which were set will be overridden.
-    pub fn vertical(mut self, c: char) -> Style<T, B, L, R, H, On, HLines, VLines>
+    pub fn vertical(
+        mut self,
+        c: impl Into<BorderSymbol>,
+    ) -> Style<T, B, L, R, H, On, HLines, VLines>
     where
         for<'a> &'a mut HLines: IntoIterator<Item = &'a mut HorizontalLine>,
     {
-        self.borders.vertical = Some(c);
+        let c = c.into();
+
+        self.borders.vertical = Some(c.clone());
 
         if self.borders.has_horizontal() {
-            self.borders.intersection = Some(c);
+            self.borders.intersection = Some(c.clone());
         }
 
         if self.borders.has_top() {
-            self.borders.top_intersection = Some(c);
+            self.borders.top_intersection = Some(c.clone());
         }
 
         if self.borders.has_bottom() {
-            self.borders.bottom_intersection = Some(c);
+            self.borders.bottom_intersection = Some(c.clone());
         }
 
         for hl in &mut self.horizontals {
             if let Some(line) = &mut hl.line {
-                line.intersection = Some(c);
+                line.intersection = Some(c.clone());
             }
         }
 
@@ -920,8 +939,8 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
 
 impl<B, R, H, V, HLines, VLines> Style<On, B, On, R, H, V, HLines, VLines> {
     /// Sets a top left corner.
-    pub fn top_left_corner(mut self, c: char) -> Self {
-        self.borders.top_left = Some(c);
+    pub fn top_left_corner(mut self, c: impl Into<BorderSymbol>) -> Self {
+        self.borders.top_left = Some(c.into());
 
         Style::new(self.borders, self.horizontals, self.verticals)
     }
@@ -929,8 +948,8 @@ impl<B, R, H, V, HLines, VLines> Style<On, B, On, R, H, V, HLines, VLines> {
 
 impl<B, L, H, V, HLines, VLines> Style<On, B, L, On, H, V, HLines, VLines> {
     /// Sets a top right corner.
-    pub fn top_right_corner(mut self, c: char) -> Self {
-        self.borders.top_right = Some(c);
+    pub fn top_right_corner(mut self, c: impl Into<BorderSymbol>) -> Self {
+        self.borders.top_right = Some(c.into());
 
         Style::new(self.borders, self.horizontals, self.verticals)
     }
@@ -938,8 +957,8 @@ impl<B, L, H, V, HLines, VLines> Style<On, B, L, On, H, V, HLines, VLines> {
 
 impl<T, L, H, V, HLines, VLines> Style<T, On, L, On, H, V, HLines, VLines> {
     /// Sets a bottom right corner.
-    pub fn bottom_right_corner(mut self, c: char) -> Self {
-        self.borders.bottom_right = Some(c);
+    pub fn bottom_right_corner(mut self, c: impl Into<BorderSymbol>) -> Self {
+        self.borders.bottom_right = Some(c.into());
 
         Style::new(self.borders, self.horizontals, self.verticals)
     }
@@ -947,8 +966,8 @@ impl<T, L, H, V, HLines, VLines> Style<T, On, L, On, H, V, HLines, VLines> {
 
 impl<T, R, H, V, HLines, VLines> Style<T, On, On, R, H, V, HLines, VLines> {
     /// Sets a bottom left corner.
-    pub fn bottom_left_corner(mut self, c: char) -> Self {
-        self.borders.bottom_left = Some(c);
+    pub fn bottom_left_corner(mut self, c: impl Into<BorderSymbol>) -> Self {
+        self.borders.bottom_left = Some(c.into());
 
         Style::new(self.borders, self.horizontals, self.verticals)
     }
@@ -956,8 +975,8 @@ impl<T, R, H, V, HLines, VLines> Style<T, On, On, R, H, V, HLines, VLines> {
 
 impl<T, B, R, V, HLines, VLines> Style<T, B, On, R, On, V, HLines, VLines> {
     /// Sets a left intersection char.
-    pub fn left_intersection(mut self, c: char) -> Self {
-        self.borders.horizontal_left = Some(c);
+    pub fn left_intersection(mut self, c: impl Into<BorderSymbol>) -> Self {
+        self.borders.horizontal_left = Some(c.into());
 
         Style::new(self.borders, self.horizontals, self.verticals)
     }
@@ -965,8 +984,8 @@ impl<T, B, R, V, HLines, VLines> Style<T, B, On, R, On, V, HLines, VLines> {
 
 impl<T, B, L, V, HLines, VLines> Style<T, B, L, On, On, V, HLines, VLines> {
     /// Sets a right intersection char.
-    pub fn right_intersection(mut self, c: char) -> Self {
-        self.borders.horizontal_right = Some(c);
+    pub fn right_intersection(mut self, c: impl Into<BorderSymbol>) -> Self {
+        self.borders.horizontal_right = Some(c.into());
 
         Style::new(self.borders, self.horizontals, self.verticals)
     }
@@ -974,8 +993,8 @@ impl<T, B, L, V, HLines, VLines> Style<T, B, L, On, On, V, HLines, VLines> {
 
 impl<B, L, R, H, HLines, VLines> Style<On, B, L, R, H, On, HLines, VLines> {
     /// Sets a top intersection char.
-    pub fn top_intersection(mut self, c: char) -> Self {
-        self.borders.top_intersection = Some(c);
+    pub fn top_intersection(mut self, c: impl Into<BorderSymbol>) -> Self {
+        self.borders.top_intersection = Some(c.into());
 
         Style::new(self.borders, self.horizontals, self.verticals)
     }
@@ -983,8 +1002,8 @@ impl<B, L, R, H, HLines, VLines> Style<On, B, L, R, H, On, HLines, VLines> {
 
 impl<T, L, R, H, HLines, VLines> Style<T, On, L, R, H, On, HLines, VLines> {
     /// Sets a bottom intersection char.
-    pub fn bottom_intersection(mut self, c: char) -> Self {
-        self.borders.bottom_intersection = Some(c);
+    pub fn bottom_intersection(mut self, c: impl Into<BorderSymbol>) -> Self {
+        self.borders.bottom_intersection = Some(c.into());
 
         Style::new(self.borders, self.horizontals, self.verticals)
     }
@@ -993,8 +1012,8 @@ impl<T, L, R, H, HLines, VLines> Style<T, On, L, R, H, On, HLines, VLines> {
 impl<T, B, L, R, HLines, VLines> Style<T, B, L, R, On, On, HLines, VLines> {
     /// Sets an inner intersection char.
     /// A char between horizontal and vertical split lines.
-    pub fn inner_intersection(mut self, c: char) -> Self {
-        self.borders.intersection = Some(c);
+    pub fn inner_intersection(mut self, c: impl Into<BorderSymbol>) -> Self {
+        self.borders.intersection = Some(c.into());
 
         Style::new(self.borders, self.horizontals, self.verticals)
     }
@@ -1109,7 +1128,7 @@ impl<T, B, L, R, H, HLines, VLines> Style<T, B, L, R, H, On, HLines, VLines> {
 }
 
 impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
-    const fn new(borders: Borders, horizontals: HLines, verticals: VLines) -> Self {
+    const fn new(borders: Borders<BorderSymbol>, horizontals: HLines, verticals: VLines) -> Self {
         Self {
             borders,
             horizontals,
@@ -1152,29 +1171,68 @@ where
 }
 
 const fn create_borders(
-    top: Line,
-    bottom: Line,
-    horizontal: Line,
+    top: LineChars,
+    bottom: LineChars,
+    horizontal: LineChars,
     left: Option<char>,
     right: Option<char>,
     vertical: Option<char>,
-) -> Borders {
+) -> Borders<BorderSymbol> {
     Borders {
-        top: top.main,
-        bottom: bottom.main,
-        top_left: top.connector1,
-        top_right: top.connector2,
-        bottom_left: bottom.connector1,
-        bottom_right: bottom.connector2,
-        top_intersection: top.intersection,
-        bottom_intersection: bottom.intersection,
-        horizontal_left: horizontal.connector1,
-        horizontal_right: horizontal.connector2,
-        horizontal: horizontal.main,
-        intersection: horizontal.intersection,
-        vertical_left: left,
-        vertical_right: right,
-        vertical,
+        top: symbol(top.main),
+        bottom: symbol(bottom.main),
+        top_left: symbol(top.connector1),
+        top_right: symbol(top.connector2),
+        bottom_left: symbol(bottom.connector1),
+        bottom_right: symbol(bottom.connector2),
+        top_intersection: symbol(top.intersection),
+        bottom_intersection: symbol(bottom.intersection),
+        horizontal_left: symbol(horizontal.connector1),
+        horizontal_right: symbol(horizontal.connector2),
+        horizontal: symbol(horizontal.main),
+        intersection: symbol(horizontal.intersection),
+        vertical_left: symbol(left),
+        vertical_right: symbol(right),
+        vertical: symbol(vertical),
+    }
+}
+
+// A [`Line`] of chars which could be used in const functions,
+// as a [`Line`] can't be dropped in them.
+#[derive(Clone, Copy)]
+struct LineChars {
+    main: Option<char>,
+    intersection: Option<char>,
+    connector1: Option<char>,
+    connector2: Option<char>,
+}
+
+impl LineChars {
+    const fn new(
+        main: Option<char>,
+        intersection: Option<char>,
+        connector1: Option<char>,
+        connector2: Option<char>,
+    ) -> Self {
+        Self {
+            main,
+            intersection,
+            connector1,
+            connector2,
+        }
+    }
+
+    const fn full(main: char, intersection: char, connector1: char, connector2: char) -> Self {
+        Self::new(
+            Some(main),
+            Some(intersection),
+            Some(connector1),
+            Some(connector2),
+        )
+    }
+
+    const fn empty() -> Self {
+        Self::new(None, None, None, None)
     }
 }
 
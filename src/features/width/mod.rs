@@ -197,8 +197,8 @@ pub(crate) fn count_borders(
 ) -> usize {
     (start..end)
         .skip(1)
-        .filter(|&i| cfg.has_vertical(i, count_columns))
-        .count()
+        .map(|i| cfg.get_vertical_width(i, count_columns))
+        .sum()
 }
 
 pub(crate) fn get_table_total_width<W, R>(records: R, cfg: &GridConfig, ctrl: &W) -> usize
@@ -207,7 +207,7 @@ where
     R: Records,
 {
     ctrl.total()
-        + cfg.count_vertical(records.count_columns())
+        + cfg.count_vertical_width(records.count_columns())
         + cfg.get_margin().left.size
         + cfg.get_margin().right.size
 }
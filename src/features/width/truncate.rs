@@ -79,7 +79,7 @@ where
         Self {
             width,
             suffix: None,
-            _priority: PhantomData::default(),
+            _priority: PhantomData,
         }
     }
 }
@@ -100,7 +100,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
         Truncate {
             width: self.width,
             suffix: Some(suff),
-            _priority: PhantomData::default(),
+            _priority: PhantomData,
         }
     }
 
@@ -112,7 +112,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
         Truncate {
             width: self.width,
             suffix: Some(suff),
-            _priority: PhantomData::default(),
+            _priority: PhantomData,
         }
     }
 
@@ -125,7 +125,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
         Truncate {
             width: self.width,
             suffix: Some(suff),
-            _priority: PhantomData::default(),
+            _priority: PhantomData,
         }
     }
 }
@@ -143,7 +143,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
         Truncate {
             width: self.width,
             suffix: self.suffix,
-            _priority: PhantomData::default(),
+            _priority: PhantomData,
         }
     }
 }
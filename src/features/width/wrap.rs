@@ -55,7 +55,7 @@ where
         Self {
             width,
             keep_words: false,
-            _priority: PhantomData::default(),
+            _priority: PhantomData,
         }
     }
 }
@@ -77,7 +77,7 @@ impl<W, P> Wrap<W, P> {
         Wrap {
             width: self.width,
             keep_words: self.keep_words,
-            _priority: PhantomData::default(),
+            _priority: PhantomData,
         }
     }
 
@@ -843,10 +843,7 @@ mod tests {
 
         println!(
             "{}",
-            split_keeping_words(
-                "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
-                3,
-            ),
+            split_keeping_words("\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m", 3,),
         );
 
         assert_eq!(
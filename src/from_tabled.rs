@@ -0,0 +1,386 @@
+use std::{borrow::Cow, fmt, str::FromStr};
+
+use crate::Tabled;
+
+/// FromTabled a trait responsible for building a value out of a row of a table.
+///
+/// It's a reverse of [`Tabled`], so a value which was displayed by [`Tabled`] could be read back.
+/// A cell of a field is looked up by a header name rather than by an index,
+/// so the order of columns doesn't matter.
+///
+/// See [`from_records`] to build a list of values.
+///
+/// [`Tabled`]: crate::Tabled
+pub trait FromTabled: Sized {
+    /// Builds a value out of a row.
+    fn from_row(row: &TabledRow<'_>) -> Result<Self, FromTabledError>;
+
+    /// Builds a value out of a row where it's displayed as a single column with a given name.
+    ///
+    /// It's used by a `#[tabled(transparent)]` newtype which is inlined into another type,
+    /// see [`Tabled::INLINE_AS_FIELD`].
+    /// By default it's [`FromTabled::from_row`].
+    ///
+    /// [`Tabled::INLINE_AS_FIELD`]: crate::Tabled::INLINE_AS_FIELD
+    fn from_column(row: &TabledRow<'_>, column: &str) -> Result<Self, FromTabledError> {
+        let _ = column;
+        Self::from_row(row)
+    }
+
+    /// Builds a value out of a row when it's inlined into another type as a field with a given name.
+    ///
+    /// By default it's [`FromTabled::from_row`].
+    fn from_inlined_row(row: &TabledRow<'_>, field: &str) -> Result<Self, FromTabledError> {
+        let _ = field;
+        Self::from_row(row)
+    }
+}
+
+impl<T> FromTabled for Box<T>
+where
+    T: FromTabled,
+{
+    fn from_row(row: &TabledRow<'_>) -> Result<Self, FromTabledError> {
+        T::from_row(row).map(Box::new)
+    }
+
+    fn from_column(row: &TabledRow<'_>, column: &str) -> Result<Self, FromTabledError> {
+        T::from_column(row, column).map(Box::new)
+    }
+
+    fn from_inlined_row(row: &TabledRow<'_>, field: &str) -> Result<Self, FromTabledError> {
+        T::from_inlined_row(row, field).map(Box::new)
+    }
+}
+
+/// `None` is read from empty cells, same as [`Tabled`] displays it.
+impl<T> FromTabled for Option<T>
+where
+    T: FromTabled + Tabled,
+{
+    fn from_row(row: &TabledRow<'_>) -> Result<Self, FromTabledError> {
+        if row.is_blank::<T>(None, "")? {
+            return Ok(None);
+        }
+
+        T::from_row(row).map(Some)
+    }
+
+    fn from_column(row: &TabledRow<'_>, column: &str) -> Result<Self, FromTabledError> {
+        if row.get(column)?.is_empty() {
+            return Ok(None);
+        }
+
+        T::from_column(row, column).map(Some)
+    }
+
+    fn from_inlined_row(row: &TabledRow<'_>, field: &str) -> Result<Self, FromTabledError> {
+        row.parse_inlined_option(field, "")
+    }
+}
+
+// A value is read from a column named after its type, same as `Tabled` names it.
+macro_rules! value_from_tabled {
+    ( $($t:ty),* ) => {
+        $(
+            impl FromTabled for $t {
+                fn from_row(row: &TabledRow<'_>) -> Result<Self, FromTabledError> {
+                    row.parse(stringify!($t))
+                }
+
+                fn from_column(row: &TabledRow<'_>, column: &str) -> Result<Self, FromTabledError> {
+                    row.parse(column)
+                }
+            }
+        )*
+    };
+}
+
+value_from_tabled!(String, char, bool);
+value_from_tabled!(isize, usize, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// Builds a list of values out of records.
+///
+/// A first record is considered to be a header, the rest of them are rows.
+///
+/// ```
+/// use tabled::{from_records, FromTabled, FromTabledError, TabledRow};
+///
+/// struct Language {
+///     name: String,
+///     year: u16,
+/// }
+///
+/// impl FromTabled for Language {
+///     fn from_row(row: &TabledRow<'_>) -> Result<Self, FromTabledError> {
+///         Ok(Self {
+///             name: row.parse("name")?,
+///             year: row.parse("year")?,
+///         })
+///     }
+/// }
+///
+/// let records = vec![vec!["year", "name"], vec!["1972", "C"], vec!["2010", "Rust"]];
+/// let languages: Vec<Language> = from_records(records).unwrap();
+///
+/// assert_eq!(languages[1].name, "Rust");
+/// assert_eq!(languages[1].year, 2010);
+/// ```
+pub fn from_records<T, R, I, S>(records: R) -> Result<Vec<T>, FromTabledError>
+where
+    T: FromTabled,
+    R: IntoIterator<Item = I>,
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut records = records.into_iter().map(|record| {
+        record
+            .into_iter()
+            .map(|text| text.as_ref().to_owned())
+            .collect::<Vec<_>>()
+    });
+
+    let header = match records.next() {
+        Some(header) => header,
+        None => return Ok(Vec::new()),
+    };
+
+    records
+        .enumerate()
+        .map(|(i, cells)| T::from_row(&TabledRow::new(i + 1, &header, &cells)))
+        .collect()
+}
+
+/// A row of a table which is used to build a [`FromTabled`] value.
+#[derive(Debug, Clone)]
+pub struct TabledRow<'a> {
+    index: usize,
+    header: &'a [String],
+    cells: &'a [String],
+    prefix: Cow<'a, str>,
+}
+
+impl<'a> TabledRow<'a> {
+    /// Creates a new row.
+    ///
+    /// An `index` is an index of the row in a table including a header,
+    /// it's used only in errors.
+    pub fn new(index: usize, header: &'a [String], cells: &'a [String]) -> Self {
+        Self {
+            index,
+            header,
+            cells,
+            prefix: Cow::Borrowed(""),
+        }
+    }
+
+    /// Returns a row where all columns are looked up with a given prefix.
+    ///
+    /// It's used to build inlined values.
+    pub fn with_prefix(&self, prefix: &str) -> TabledRow<'a> {
+        TabledRow {
+            index: self.index,
+            header: self.header,
+            cells: self.cells,
+            prefix: Cow::Owned(format!("{}{}", self.prefix, prefix)),
+        }
+    }
+
+    /// Returns an index of the row.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a text of a cell in a given column.
+    pub fn get(&self, column: &str) -> Result<&'a str, FromTabledError> {
+        let (_, text) = self.lookup(column)?;
+        Ok(text)
+    }
+
+    /// Parses a cell in a given column.
+    pub fn parse<T>(&self, column: &str) -> Result<T, FromTabledError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let (col, text) = self.lookup(column)?;
+        text.parse().map_err(|err: T::Err| {
+            self.error(
+                Some(col),
+                column,
+                FromTabledErrorKind::Parse {
+                    text: text.to_owned(),
+                    message: err.to_string(),
+                },
+            )
+        })
+    }
+
+    /// Parses a cell in a given column,
+    /// where a `placeholder` text means `None`.
+    pub fn parse_option<T>(
+        &self,
+        column: &str,
+        placeholder: &str,
+    ) -> Result<Option<T>, FromTabledError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        if self.get(column)? == placeholder {
+            return Ok(None);
+        }
+
+        self.parse(column).map(Some)
+    }
+
+    /// Builds a value which is inlined as a field with a given name,
+    /// where all of its cells being a `placeholder` text means `None`.
+    pub fn parse_inlined_option<T>(
+        &self,
+        field: &str,
+        placeholder: &str,
+    ) -> Result<Option<T>, FromTabledError>
+    where
+        T: FromTabled + Tabled,
+    {
+        if self.is_blank::<T>(Some(field), placeholder)? {
+            return Ok(None);
+        }
+
+        T::from_inlined_row(self, field).map(Some)
+    }
+
+    // Checks whether all columns of a type are a given placeholder,
+    // a type which is inlined as a field is displayed in a single column named after the field.
+    fn is_blank<T>(&self, field: Option<&str>, placeholder: &str) -> Result<bool, FromTabledError>
+    where
+        T: Tabled,
+    {
+        if let (true, Some(field)) = (T::INLINE_AS_FIELD, field) {
+            return Ok(self.get(field)? == placeholder);
+        }
+
+        let headers = T::headers();
+        if headers.is_empty() {
+            return Ok(false);
+        }
+
+        for column in &headers {
+            if self.get(column)? != placeholder {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn lookup(&self, column: &str) -> Result<(usize, &'a str), FromTabledError> {
+        let col = self
+            .header
+            .iter()
+            .position(|name| {
+                name.len() == self.prefix.len() + column.len()
+                    && name.starts_with(self.prefix.as_ref())
+                    && name.ends_with(column)
+            })
+            .ok_or_else(|| self.error(None, column, FromTabledErrorKind::MissingColumn))?;
+
+        let text = self
+            .cells
+            .get(col)
+            .ok_or_else(|| self.error(Some(col), column, FromTabledErrorKind::MissingCell))?;
+
+        Ok((col, text))
+    }
+
+    fn error(
+        &self,
+        col: Option<usize>,
+        column: &str,
+        kind: FromTabledErrorKind,
+    ) -> FromTabledError {
+        FromTabledError {
+            row: self.index,
+            column: col,
+            header: format!("{}{}", self.prefix, column),
+            kind,
+        }
+    }
+}
+
+/// An error which is returned when a [`FromTabled`] value can't be built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromTabledError {
+    row: usize,
+    column: Option<usize>,
+    header: String,
+    kind: FromTabledErrorKind,
+}
+
+impl FromTabledError {
+    /// Returns an index of a row, see [`TabledRow::new`].
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Returns an index of a column,
+    /// it's `None` in case the column is missing.
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    /// Returns a header of a column.
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    /// Returns a kind of the error.
+    pub fn kind(&self) -> &FromTabledErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for FromTabledError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            FromTabledErrorKind::MissingColumn => {
+                write!(f, "row {}: column {:?} is missing", self.row, self.header)
+            }
+            FromTabledErrorKind::MissingCell => write!(
+                f,
+                "row {}, column {}: a cell of column {:?} is missing",
+                self.row,
+                self.column.unwrap_or_default(),
+                self.header
+            ),
+            FromTabledErrorKind::Parse { text, message } => write!(
+                f,
+                "row {}, column {}: failed to parse {:?} in column {:?}: {}",
+                self.row,
+                self.column.unwrap_or_default(),
+                text,
+                self.header,
+                message
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FromTabledError {}
+
+/// A kind of a [`FromTabledError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromTabledErrorKind {
+    /// A header has no such column.
+    MissingColumn,
+    /// A row has less cells than a header.
+    MissingCell,
+    /// A cell can't be parsed.
+    Parse {
+        /// A text of the cell.
+        text: String,
+        /// A parse error message.
+        message: String,
+    },
+}
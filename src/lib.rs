@@ -101,6 +101,27 @@
 //! # assert_eq!(table.to_string(), expected);
 //! ```
 //!
+//! It's also implemented for `Option` (`None` is displayed as empty cells),
+//! `Result` (a value is followed by an `error` column), arrays,
+//! `Box`, `Rc`, `Arc`, `Cow` and tuples of up to 12 elements.
+//! A map could be displayed as `key` and `value` columns by [`Table::from_map`].
+//!
+//! ```rust
+//! use std::collections::BTreeMap;
+//! use tabled::Table;
+//!
+//! let map = BTreeMap::from([("a", 1), ("b", 2)]);
+//! let table = Table::from_map(&map);
+//! # let expected = "+-----+-------+\n\
+//! #                 | key | value |\n\
+//! #                 +-----+-------+\n\
+//! #                 | a   | 1     |\n\
+//! #                 +-----+-------+\n\
+//! #                 | b   | 2     |\n\
+//! #                 +-----+-------+";
+//! # assert_eq!(table.to_string(), expected);
+//! ```
+//!
 //! ### Combination of types via tuples
 //!
 //! Personally I consider this a feature which drives the library to shine.
@@ -239,6 +260,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod features;
+mod from_tabled;
 mod modify;
 mod table;
 mod table_iterator_ext;
@@ -275,6 +297,7 @@ pub use crate::{
         style::{self, Border, BorderText, Style},
         width::{self, Width},
     },
+    from_tabled::{from_records, FromTabled, FromTabledError, FromTabledErrorKind, TabledRow},
     modify::{CellSettingsList, Modify, ModifyList, ModifyObject},
     table::{CellOption, Table, TableOption},
     table_iterator_ext::TableIteratorExt,
@@ -406,6 +429,69 @@ pub use crate::features::{color, highlight, margin_color, padding_color};
 /// }
 /// ```
 ///
+/// A trivial formatting could be done by `#[tabled(format = "{:.2}")]` attribute,
+/// where a field is an argument of a format string.
+/// Arguments could also be set explicitly using `#[tabled(format("{} ms", self.latency))]`.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// pub struct Request {
+///     #[tabled(format = "{:.2}")]
+///     pub load: f64,
+///     #[tabled(format("{} ms", self.latency))]
+///     pub latency: u64,
+/// }
+/// ```
+///
+/// An `Option` field could be rendered using `#[tabled(display_option = "-")]`,
+/// where `Some` value is displayed as it is and `None` is replaced by a given placeholder.
+/// The attribute could be set for a whole type, then it's used for all `Option` fields.
+/// It also works together with `#[tabled(inline)]`, filling all inlined columns by a placeholder.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// #[tabled(display_option = "-")]
+/// pub struct Employee {
+///     pub name: &'static str,
+///     pub age: Option<u8>,
+///     #[tabled(inline, display_option = "")]
+///     pub manager: Option<Manager>,
+/// }
+///
+/// #[derive(Tabled)]
+/// pub struct Manager {
+///     pub manager_name: &'static str,
+///     pub manager_email: &'static str,
+/// }
+/// ```
+///
+/// A collection of `Tabled` values could be rendered as an inner table using `#[tabled(nested)]`,
+/// a style of the table could be set by `#[tabled(nested("modern"))]`.
+/// A collection of `Display` values could be joined by a separator using `#[tabled(join = ", ")]`.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// pub struct Post {
+///     pub title: &'static str,
+///     #[tabled(join = ", ")]
+///     pub authors: Vec<&'static str>,
+///     #[tabled(nested("psql"))]
+///     pub comments: Vec<Comment>,
+/// }
+///
+/// #[derive(Tabled)]
+/// pub struct Comment {
+///     pub author: &'static str,
+///     pub text: &'static str,
+/// }
+/// ```
+///
 /// ### Format headers
 ///
 /// Beside `#[tabled(rename = "")]` you can change a format of a column name using
@@ -473,7 +559,320 @@ pub use crate::features::{color, highlight, margin_color, padding_color};
 ///     price: f32,
 /// }
 /// ```
+///
+/// An inlined struct could be displayed under a header which spans all of its columns using `#[tabled(inline, group)]`,
+/// the header is a name of the field. It's supported only for structs.
+///
+/// ```rust,no_run
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Line {
+///     name: &'static str,
+///     #[tabled(inline, group)]
+///     start: Point,
+///     #[tabled(inline, group)]
+///     end: Point,
+/// }
+///
+/// let line = Line { name: "line", start: Point { x: 0, y: 0 }, end: Point { x: 10, y: 20 } };
+/// let table = Table::new([line]).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+------+---+---+----+----+\n\
+///      | name | start | end     |\n\
+///      +      +---+---+----+----+\n\
+///      |      | x | y | x  | y  |\n\
+///      +------+---+---+----+----+\n\
+///      | line | 0 | 0 | 10 | 20 |\n\
+///      +------+---+---+----+----+"
+/// );
+/// ```
+///
+/// ### Enum display
+///
+/// By default each variant of an enum is a separate column.
+/// Using `#[tabled(enum_display = "name")]` an enum is displayed as a single column with a name of a variant,
+/// `rename` and `rename_all` attributes are applied to the names.
+///
+/// Using `#[tabled(enum_display = "union")]` the column with a name is followed by fields of all variants,
+/// where fields with the same name share a column and absent fields are left empty.
+/// Column settings and `rename_key` of a field are applied to its column,
+/// they can't be set on a variant as the name column is shared by all variants.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// #[tabled(enum_display = "union", rename_all = "lowercase")]
+/// enum Shape {
+///     Circle { x: i32, y: i32, radius: u32 },
+///     Point { x: i32, y: i32 },
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// #[tabled(enum_display = "name")]
+/// enum Status {
+///     #[tabled(align = "right")]
+///     Active,
+///     Inactive,
+/// }
+/// ```
+///
+/// ### Transparent
+///
+/// A type with a single field could be displayed as the field itself using `#[tabled(transparent)]`,
+/// so it has the same columns as the field. Other fields must be skipped.
+///
+/// A unit struct is displayed as a single column, like a unit variant of an enum.
+///
+/// An inlined transparent newtype over a single column, like `struct UserId(u64)`,
+/// is displayed as a column named after the field it's inlined in.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// #[tabled(transparent)]
+/// struct Admin(User);
+///
+/// #[derive(Tabled)]
+/// struct User {
+///     id: u64,
+///     name: &'static str,
+/// }
+/// ```
+///
+/// ### Column settings
+///
+/// A column alignment, width and color could be set next to a field,
+/// they are applied by `Table::new` and `TableIteratorExt::table`.
+///
+/// - `#[tabled(align = "right")]` sets an alignment, one of `left`, `right` or `center`.
+/// - `#[tabled(max_width = 20)]` truncates a content to a given width,
+///   a suffix of a truncated text could be set by `#[tabled(max_width = 20, truncate = "…")]`.
+/// - `#[tabled(color = "red")]` colors a content, a color is a name of a `tabled::color::Color`
+///   constant in lower case, like `red`, `bright_red` or `bg_bright_blue`.
+///   It requires `color` feature, so widths of a colored content are calculated correctly.
+///
+/// When set for an inlined field or variant, the settings are applied to all its columns.
+///
+/// ```rust,no_run
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Request {
+///     #[tabled(max_width = 20, truncate = "…")]
+///     url: &'static str,
+///     #[tabled(align = "right")]
+///     latency: u64,
+/// }
+///
+/// let table = Table::new([Request { url: "https://github.com/zhiburt/tabled", latency: 12 }]);
+/// ```
+///
+/// Without `color` feature the `color` attribute is a compile error.
+///
+#[cfg_attr(feature = "color", doc = "```rust,no_run")]
+#[cfg_attr(not(feature = "color"), doc = "```rust,compile_fail")]
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Log {
+///     #[tabled(color = "red")]
+///     level: &'static str,
+/// }
+///
+/// let table = Table::new([Log { level: "error" }]);
+/// ```
+///
+/// An unknown color is a compile error as well.
+///
+/// ```rust,compile_fail
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Log {
+///     #[tabled(color = "purple")]
+///     level: &'static str,
+/// }
+///
+/// let table = Table::new([Log { level: "error" }]);
+/// ```
+///
+/// ### Localized headers
+///
+/// Headers could be localized at runtime using `Table::new_with_headers`,
+/// which looks up each header by a key.
+/// A key of a field could be set by `#[tabled(rename_key = "user.name")]`,
+/// otherwise a header itself is used as a key.
+/// Keys of an inlined type are prefixed by the `inline` prefix same as headers.
+///
+/// ```rust,no_run
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct User {
+///     #[tabled(rename_key = "user.name")]
+///     name: &'static str,
+///     #[tabled(rename_key = "user.age")]
+///     age: u8,
+/// }
+///
+/// let translate = |key: &str| match key {
+///     "user.name" => Some(String::from("Nom")),
+///     "user.age" => Some(String::from("Âge")),
+///     _ => None,
+/// };
+///
+/// let table = Table::new_with_headers([User { name: "Alice", age: 30 }], translate);
+/// ```
+///
+/// ### Dynamic columns
+///
+/// A map field could be expanded into columns using `#[tabled(flatten_map)]`.
+/// The columns are a sorted union of keys of all rows, they are placed after the other columns,
+/// and a cell is empty when a row has no such key.
+///
+/// ```rust,no_run
+/// use std::collections::HashMap;
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Pod {
+///     name: &'static str,
+///     #[tabled(flatten_map)]
+///     labels: HashMap<String, String>,
+/// }
+///
+/// let pods = vec![
+///     Pod { name: "web", labels: HashMap::from([("tier".into(), "frontend".into())]) },
+///     Pod { name: "db", labels: HashMap::from([("app".into(), "shop".into())]) },
+/// ];
+///
+/// let table = Table::new(pods).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+------+------+----------+\n\
+///      | name | app  | tier     |\n\
+///      +------+------+----------+\n\
+///      | web  |      | frontend |\n\
+///      +------+------+----------+\n\
+///      | db   | shop |          |\n\
+///      +------+------+----------+"
+/// );
+/// ```
+///
+/// ### Column ids
+///
+/// Using `#[tabled(column_ids)]` a struct gets a `COLUMNS` constant with a column of each field,
+/// which takes `skip`, `order` and `inline` attributes into account.
+/// So a column could be referenced without relying on its index or header.
+/// A column of an inlined field covers all its columns.
+///
+/// ```rust,no_run
+/// use tabled::{Alignment, Disable, Modify, Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// #[tabled(column_ids)]
+/// struct User {
+///     #[tabled(skip)]
+///     id: usize,
+///     name: &'static str,
+///     #[tabled(order = 0)]
+///     email: &'static str,
+/// }
+///
+/// let users = [User { id: 0, name: "Alice", email: "alice@example.com" }];
+///
+/// let table = Table::new(users)
+///     .with(Modify::new(User::COLUMNS.name).with(Alignment::right()))
+///     .with(Disable::column(User::COLUMNS.email))
+///     .to_string();
+/// ```
+///
+/// It can't be used together with `transparent` or `enum_display` as such a type has no field columns.
+///
+/// ```rust,compile_fail
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// #[tabled(column_ids, enum_display = "name")]
+/// enum Status {
+///     Active,
+///     Inactive,
+/// }
+/// ```
 // @todo: Move the comment to tabled_derive
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use tabled_derive::Tabled;
+
+/// A derive to implement a [`FromTabled`] trait.
+///
+/// It's a reverse of the [`Tabled`](derive@Tabled) derive,
+/// a field is looked up by the same header name and parsed by [`FromStr`](std::str::FromStr).
+/// The `rename`, `rename_all`, `skip`, `order`, `inline`, `transparent` and `display_option` attributes are supported.
+/// A skipped field is set by [`Default`].
+///
+/// ```rust
+/// use tabled::{from_records, FromTabled, Tabled};
+///
+/// #[derive(Tabled, FromTabled, Debug, PartialEq)]
+/// #[tabled(rename_all = "UPPERCASE")]
+/// struct Language {
+///     name: String,
+///     #[tabled(rename = "released")]
+///     year: u16,
+///     #[tabled(skip)]
+///     notes: String,
+/// }
+///
+/// let records = [["NAME", "released"], ["Rust", "2010"]];
+/// let languages: Vec<Language> = from_records(records).unwrap();
+///
+/// assert_eq!(
+///     languages,
+///     [Language { name: "Rust".into(), year: 2010, notes: String::new() }],
+/// );
+/// ```
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use tabled_derive::FromTabled;
+
+// It's used by `#[tabled(color = "...")]` of the `Tabled` derive.
+//
+// A colored text is measured correctly only with `color` feature,
+// so without it the attribute is a compile error rather than a misaligned table.
+#[cfg(feature = "color")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __derive_color {
+    ( $color:ident ) => {{
+        let color = $crate::papergrid::AnsiColor::from($crate::color::Color::$color);
+        $crate::format::Format::multiline(move |text| {
+            format!("{}{}{}", color.get_prefix(), text, color.get_suffix())
+        })
+    }};
+}
+
+#[cfg(not(feature = "color"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __derive_color {
+    ( $color:ident ) => {
+        compile_error!("`#[tabled(color = \"...\")]` requires `color` feature of `tabled`")
+    };
+}
@@ -440,7 +440,7 @@ where
 /// Column denotes a set of cells on given columns on a [`Table`].
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Columns<R> {
     range: R,
 }
@@ -452,7 +452,7 @@ where
     /// Returns a new instance of [`Columns`] for a range of columns.
     ///
     /// If the boundaries are exceeded it may panic.
-    pub fn new(range: R) -> Self {
+    pub const fn new(range: R) -> Self {
         Self { range }
     }
 
@@ -465,7 +465,7 @@ impl Columns<()> {
     /// Returns a new instance of [`Columns`] for a single column.
     ///
     /// If the boundaries are exceeded it may panic.
-    pub fn single(index: usize) -> Column {
+    pub const fn single(index: usize) -> Column {
         Column(index)
     }
 
@@ -484,6 +484,19 @@ impl Columns<()> {
     }
 }
 
+impl<R> RangeBounds<usize> for Columns<R>
+where
+    R: RangeBounds<usize>,
+{
+    fn start_bound(&self) -> Bound<&usize> {
+        self.range.start_bound()
+    }
+
+    fn end_bound(&self) -> Bound<&usize> {
+        self.range.end_bound()
+    }
+}
+
 impl<R> Object for Columns<R>
 where
     R: RangeBounds<usize>,
@@ -582,6 +595,16 @@ impl Object for Column {
     }
 }
 
+impl RangeBounds<usize> for Column {
+    fn start_bound(&self) -> Bound<&usize> {
+        Bound::Included(&self.0)
+    }
+
+    fn end_bound(&self) -> Bound<&usize> {
+        Bound::Included(&self.0)
+    }
+}
+
 impl From<usize> for Column {
     fn from(i: usize) -> Self {
         Self(i)
@@ -1,6 +1,11 @@
 //! This module contains a main table representation of this crate [`Table`].
 
-use std::{borrow::Cow, fmt, iter::FromIterator};
+use std::{
+    borrow::Cow,
+    collections::BTreeSet,
+    fmt::{self, Write},
+    iter::FromIterator,
+};
 
 use papergrid::{
     height::HeightEstimator,
@@ -96,32 +101,104 @@ impl Table<VecRecords<CellInfo<'static>>> {
         I: IntoIterator<Item = T>,
         T: Tabled,
     {
-        let ctrl = CfgWidthFunction::new(4);
+        Self::from_tabled(iter, T::headers())
+    }
 
-        let mut header = vec![CellInfo::default(); T::LENGTH];
-        for (text, cell) in T::headers().into_iter().zip(header.iter_mut()) {
-            CellMut::set(cell, text, &ctrl);
-        }
+    /// Creates a Table instance with localized headers.
+    ///
+    /// A `lookup` function is called with each key of [`Tabled::header_keys`],
+    /// in case it returns `None` a header from [`Tabled::headers`] is used.
+    ///
+    /// ```
+    /// use tabled::{Table, Tabled};
+    ///
+    /// #[derive(Tabled)]
+    /// struct User {
+    ///     #[tabled(rename_key = "user.name")]
+    ///     name: &'static str,
+    ///     #[tabled(rename_key = "user.age")]
+    ///     age: u8,
+    /// }
+    ///
+    /// let lookup = |key: &str| match key {
+    ///     "user.name" => Some(String::from("Nom")),
+    ///     _ => None,
+    /// };
+    ///
+    /// let table = Table::new_with_headers([User { name: "Alice", age: 30 }], lookup);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+-----+\n\
+    ///      | Nom   | age |\n\
+    ///      +-------+-----+\n\
+    ///      | Alice | 30  |\n\
+    ///      +-------+-----+"
+    /// );
+    /// ```
+    pub fn new_with_headers<I, T, F>(iter: I, mut lookup: F) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Tabled,
+        F: FnMut(&str) -> Option<String>,
+    {
+        let headers = T::headers()
+            .into_iter()
+            .zip(T::header_keys())
+            .map(|(header, key)| lookup(&key).map_or(header, Cow::Owned))
+            .collect();
 
-        let mut records = vec![header];
-        for row in iter.into_iter() {
-            let mut list = vec![CellInfo::default(); T::LENGTH];
-            for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
-                CellMut::set(cell, text.into_owned(), &ctrl);
-            }
+        Self::from_tabled(iter, headers)
+    }
 
-            records.push(list);
-        }
+    /// Creates a Table instance out of a map, with a row per entry
+    /// and `key` and `value` columns.
+    ///
+    /// Rows follow an iteration order of the map.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use tabled::Table;
+    ///
+    /// let map = BTreeMap::from([("a", 1), ("b", 2)]);
+    /// let table = Table::from_map(&map);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----+-------+\n\
+    ///      | key | value |\n\
+    ///      +-----+-------+\n\
+    ///      | a   | 1     |\n\
+    ///      +-----+-------+\n\
+    ///      | b   | 2     |\n\
+    ///      +-----+-------+"
+    /// );
+    /// ```
+    pub fn from_map<I, K, V>(map: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: fmt::Display,
+        V: fmt::Display,
+    {
+        Self::new(map.into_iter().map(|(key, value)| MapEntry(key, value)))
+    }
 
-        let mut b = Builder::custom(VecRecords::from(records));
-        b.with_header();
-        b.build()
+    fn from_tabled<I, T>(iter: I, headers: Vec<Cow<'static, str>>) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Tabled,
+    {
+        tabled_builder(iter, headers, build_row).build()
     }
 }
 
 impl Table<()> {
     /// Creates a builder from a data set given.
     ///
+    /// A built table has the same layout as [`Table::new`] would have,
+    /// though header groups and column settings of [`Tabled`] are dropped
+    /// once columns are rearranged by [`Builder::index`] or [`Builder::clean`].
+    ///
     /// # Example
     ///
     ///
@@ -172,22 +249,7 @@ impl Table<()> {
         T: Tabled,
         I: IntoIterator<Item = T>,
     {
-        let ctrl = CfgWidthFunction::new(4);
-        let mut records = Vec::new();
-        for row in iter {
-            let mut list = vec![CellInfo::default(); T::LENGTH];
-            for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
-                CellMut::set(cell, text.into_owned(), &ctrl);
-            }
-
-            records.push(list);
-        }
-
-        let mut b = Builder::from(records);
-        b.hint_column_size(T::LENGTH);
-        b.set_columns(T::headers());
-
-        b
+        tabled_builder(iter, T::headers(), build_row)
     }
 }
 
@@ -370,27 +432,161 @@ where
     where
         I: IntoIterator<Item = &'a T>,
     {
-        let ctrl = CfgWidthFunction::new(4);
+        tabled_builder(iter, T::headers(), |row, ctrl| {
+            build_borrowed_row(*row, ctrl)
+        })
+        .build()
+    }
+}
+
+// An entry of a map which is displayed as `key` and `value` columns.
+struct MapEntry<K, V>(K, V);
+
+impl<K, V> Tabled for MapEntry<K, V>
+where
+    K: fmt::Display,
+    V: fmt::Display,
+{
+    const LENGTH: usize = 2;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![
+            Cow::Owned(self.0.to_string()),
+            Cow::Owned(self.1.to_string()),
+        ]
+    }
+
+    fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn fmt::Display)) {
+        visitor(&self.0);
+        visitor(&self.1);
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("key"), Cow::Borrowed("value")]
+    }
+}
+
+// Builds a table out of values,
+// so a header, dynamic columns, header groups and column settings
+// are the same for all constructors.
+fn tabled_builder<'a, I, T, F>(
+    iter: I,
+    headers: Vec<Cow<'static, str>>,
+    build_row: F,
+) -> Builder<'a>
+where
+    I: IntoIterator<Item = T>,
+    T: Tabled,
+    F: FnMut(&T, &CfgWidthFunction) -> Vec<CellInfo<'a>>,
+{
+    let ctrl = CfgWidthFunction::new(4);
+    let (rows, keys) = build_rows(iter, &ctrl, build_row);
+
+    let mut groups = T::header_groups();
+    if !groups.is_empty() && !keys.is_empty() {
+        groups.push((Cow::Borrowed(""), keys.len()));
+    }
 
-        let mut header = vec![CellInfo::default(); T::LENGTH];
-        for (text, cell) in T::headers().into_iter().zip(header.iter_mut()) {
-            CellMut::set(cell, text, &ctrl);
+    let mut headers: Vec<Cow<'a, str>> = headers;
+    headers.extend(keys.into_iter().map(Cow::Owned));
+
+    let mut b = Builder::from(rows);
+    b.hint_column_size(headers.len());
+    b.set_columns(headers);
+    b.set_header_groups(groups);
+    b.set_column_settings::<T>();
+
+    b
+}
+
+// Builds rows out of values,
+// dynamic fields are added as columns after the static ones,
+// which are a sorted union of their headers.
+fn build_rows<'a, I, T, F>(
+    iter: I,
+    ctrl: &CfgWidthFunction,
+    mut build_row: F,
+) -> (Vec<Vec<CellInfo<'a>>>, Vec<String>)
+where
+    I: IntoIterator<Item = T>,
+    T: Tabled,
+    F: FnMut(&T, &CfgWidthFunction) -> Vec<CellInfo<'a>>,
+{
+    let mut rows = Vec::new();
+    let mut dynamic = Vec::new();
+    for row in iter {
+        rows.push(build_row(&row, ctrl));
+
+        let fields = row.dynamic_fields();
+        if !fields.is_empty() {
+            let fields = fields
+                .into_iter()
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect::<Vec<_>>();
+            dynamic.push((rows.len() - 1, fields));
         }
+    }
 
-        let mut records = vec![header];
-        for row in iter.into_iter() {
-            let mut list = vec![CellInfo::default(); T::LENGTH];
-            for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
-                CellMut::set(cell, text, &ctrl);
-            }
+    if dynamic.is_empty() {
+        return (rows, Vec::new());
+    }
+
+    let keys = dynamic
+        .iter()
+        .flat_map(|(_, fields)| fields.iter().map(|(key, _)| key.clone()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
 
-            records.push(list);
+    for row in &mut rows {
+        row.resize(T::LENGTH + keys.len(), CellInfo::default());
+    }
+
+    for (i, fields) in dynamic {
+        for (key, value) in fields {
+            if let Ok(col) = keys.binary_search(&key) {
+                CellMut::set(&mut rows[i][T::LENGTH + col], value, ctrl);
+            }
         }
+    }
+
+    (rows, keys)
+}
 
-        let mut b = Builder::custom(VecRecords::from(records));
-        b.with_header();
-        b.build()
+// A row which borrows its fields from a value.
+fn build_borrowed_row<'a, T>(row: &'a T, ctrl: &CfgWidthFunction) -> Vec<CellInfo<'a>>
+where
+    T: Tabled,
+{
+    let mut list = vec![CellInfo::default(); T::LENGTH];
+    for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
+        CellMut::set(cell, text, ctrl);
     }
+
+    list
+}
+
+fn build_row<T>(row: &T, ctrl: &CfgWidthFunction) -> Vec<CellInfo<'static>>
+where
+    T: Tabled,
+{
+    let mut list = vec![CellInfo::default(); T::LENGTH];
+
+    // A field is formatted into a reused buffer,
+    // so a cell text is allocated once with an exact capacity.
+    let mut buf = String::new();
+    let mut i = 0;
+    row.visit_fields(&mut |field| {
+        if let Some(cell) = list.get_mut(i) {
+            buf.clear();
+            let _ = write!(buf, "{}", field);
+            CellMut::set(cell, buf.as_str().to_owned(), ctrl);
+        }
+
+        i += 1;
+    });
+
+    list
 }
 
 #[derive(Debug)]
@@ -1,4 +1,8 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt::Display, rc::Rc, sync::Arc};
+
+use papergrid::records::{Records, RecordsMut};
+
+use crate::Table;
 
 /// Tabled a trait responsible for providing a header fields and a row fields.
 ///
@@ -12,40 +16,261 @@ pub trait Tabled {
     /// which must be the same.
     const LENGTH: usize;
 
+    /// Whether the type is displayed as a single column named after a field
+    /// when it's inlined into another type.
+    ///
+    /// It's `true` for a `#[tabled(transparent)]` newtype over a single column,
+    /// by default it's `false` so the headers of the type are used.
+    const INLINE_AS_FIELD: bool = false;
+
     /// Fields method must return a list of cells.
     ///
     /// The cells will be placed in the same row, preserving the order.
     fn fields(&self) -> Vec<Cow<'_, str>>;
     /// Headers must return a list of column names.
     fn headers() -> Vec<Cow<'static, str>>;
+
+    /// Visits each field of a row in the same order as [`Tabled::fields`] returns them.
+    ///
+    /// It's an alternative to [`Tabled::fields`] which doesn't collect fields into a list of strings,
+    /// it's used by [`Table::new`] to format each field right into a cell.
+    ///
+    /// By default it visits [`Tabled::fields`].
+    fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn Display)) {
+        for field in self.fields() {
+            visitor(&field);
+        }
+    }
+
+    /// Dynamic fields of a row as a list of header and value pairs.
+    ///
+    /// [`Table::new`] adds them as columns after the columns of [`Tabled::headers`],
+    /// the columns are a sorted union of headers of all rows,
+    /// and a cell is empty if a row has no such field.
+    ///
+    /// By default there's no dynamic fields.
+    fn dynamic_fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    /// Keys of headers which are used to look up localized headers,
+    /// see [`Table::new_with_headers`].
+    ///
+    /// It must have the same length as [`Tabled::headers`].
+    /// By default the keys are the headers themselves.
+    fn header_keys() -> Vec<Cow<'static, str>> {
+        Self::headers()
+    }
+
+    /// Groups of columns which are displayed as an additional header row above [`Tabled::headers`].
+    ///
+    /// Each group is a name and a number of columns it spans, in a columns order.
+    /// A group with an empty name means that the columns aren't grouped,
+    /// so their headers take both header rows.
+    ///
+    /// By default there's no groups.
+    fn header_groups() -> Vec<(Cow<'static, str>, usize)> {
+        Vec::new()
+    }
+
+    /// Configures columns of a table which were built from the type,
+    /// like their alignment, width or color.
+    ///
+    /// `offset` is an index of a first column of the type in a table.
+    ///
+    /// It's called by [`Table::new`] and the other constructors out of [`Tabled`] values,
+    /// and by default it does nothing.
+    fn column_settings<R>(table: &mut Table<R>, offset: usize)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        let _ = (table, offset);
+    }
 }
 
-impl<T> Tabled for &T
+macro_rules! pointer_table {
+    ( $t:ty ) => {
+        impl<T> Tabled for $t
+        where
+            T: Tabled,
+        {
+            const LENGTH: usize = T::LENGTH;
+            const INLINE_AS_FIELD: bool = T::INLINE_AS_FIELD;
+
+            fn fields(&self) -> Vec<Cow<'_, str>> {
+                T::fields(self)
+            }
+            fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn Display)) {
+                T::visit_fields(self, visitor)
+            }
+            fn headers() -> Vec<Cow<'static, str>> {
+                T::headers()
+            }
+            fn header_keys() -> Vec<Cow<'static, str>> {
+                T::header_keys()
+            }
+            fn dynamic_fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+                T::dynamic_fields(self)
+            }
+            fn header_groups() -> Vec<(Cow<'static, str>, usize)> {
+                T::header_groups()
+            }
+            fn column_settings<R>(table: &mut Table<R>, offset: usize)
+            where
+                R: Records + RecordsMut<String>,
+            {
+                T::column_settings(table, offset)
+            }
+        }
+    };
+}
+
+pointer_table!(&T);
+pointer_table!(Box<T>);
+pointer_table!(Rc<T>);
+pointer_table!(Arc<T>);
+
+impl<T> Tabled for Cow<'_, T>
 where
-    T: Tabled,
+    T: Tabled + ToOwned + ?Sized,
 {
     const LENGTH: usize = T::LENGTH;
+    const INLINE_AS_FIELD: bool = T::INLINE_AS_FIELD;
 
     fn fields(&self) -> Vec<Cow<'_, str>> {
         T::fields(self)
     }
+    fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn Display)) {
+        T::visit_fields(self, visitor)
+    }
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn header_keys() -> Vec<Cow<'static, str>> {
+        T::header_keys()
+    }
+    fn dynamic_fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        T::dynamic_fields(self)
+    }
+    fn header_groups() -> Vec<(Cow<'static, str>, usize)> {
+        T::header_groups()
+    }
+    fn column_settings<R>(table: &mut Table<R>, offset: usize)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        T::column_settings(table, offset)
+    }
 }
 
-impl<T> Tabled for Box<T>
+/// `None` is displayed as empty cells.
+impl<T> Tabled for Option<T>
 where
     T: Tabled,
 {
     const LENGTH: usize = T::LENGTH;
+    const INLINE_AS_FIELD: bool = T::INLINE_AS_FIELD;
 
     fn fields(&self) -> Vec<Cow<'_, str>> {
-        T::fields(self)
+        match self {
+            Some(value) => value.fields(),
+            None => vec![Cow::Borrowed(""); T::LENGTH],
+        }
+    }
+    fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn Display)) {
+        match self {
+            Some(value) => value.visit_fields(visitor),
+            None => (0..T::LENGTH).for_each(|_| visitor(&"")),
+        }
     }
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn header_keys() -> Vec<Cow<'static, str>> {
+        T::header_keys()
+    }
+    fn dynamic_fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        match self {
+            Some(value) => value.dynamic_fields(),
+            None => Vec::new(),
+        }
+    }
+    fn header_groups() -> Vec<(Cow<'static, str>, usize)> {
+        T::header_groups()
+    }
+    fn column_settings<R>(table: &mut Table<R>, offset: usize)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        T::column_settings(table, offset)
+    }
+}
+
+/// A value is followed by an `error` column;
+/// value cells are empty in case of an error and vice versa.
+impl<T, E> Tabled for Result<T, E>
+where
+    T: Tabled,
+    E: Display,
+{
+    const LENGTH: usize = T::LENGTH + 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        match self {
+            Ok(value) => {
+                let mut fields = value.fields();
+                fields.push(Cow::Borrowed(""));
+                fields
+            }
+            Err(err) => {
+                let mut fields = vec![Cow::Borrowed(""); T::LENGTH];
+                fields.push(Cow::Owned(err.to_string()));
+                fields
+            }
+        }
+    }
+    fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn Display)) {
+        match self {
+            Ok(value) => {
+                value.visit_fields(visitor);
+                visitor(&"");
+            }
+            Err(err) => {
+                (0..T::LENGTH).for_each(|_| visitor(&""));
+                visitor(err);
+            }
+        }
+    }
+    fn headers() -> Vec<Cow<'static, str>> {
+        let mut headers = T::headers();
+        headers.push(Cow::Borrowed("error"));
+        headers
+    }
+    fn header_keys() -> Vec<Cow<'static, str>> {
+        let mut keys = T::header_keys();
+        keys.push(Cow::Borrowed("error"));
+        keys
+    }
+    fn dynamic_fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        match self {
+            Ok(value) => value.dynamic_fields(),
+            Err(_) => Vec::new(),
+        }
+    }
+    fn header_groups() -> Vec<(Cow<'static, str>, usize)> {
+        let mut groups = T::header_groups();
+        if !groups.is_empty() {
+            groups.push((Cow::Borrowed(""), 1));
+        }
+
+        groups
+    }
+    fn column_settings<R>(table: &mut Table<R>, offset: usize)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        T::column_settings(table, offset)
+    }
 }
 
 macro_rules! tuple_table {
@@ -61,11 +286,66 @@ macro_rules! tuple_table {
                 fields
             }
 
+            fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn Display)) {
+                #![allow(non_snake_case)]
+                let ($($name,)+) = self;
+                $($name.visit_fields(visitor);)+
+            }
+
             fn headers() -> Vec<Cow<'static, str>> {
                 let mut fields = Vec::with_capacity(Self::LENGTH);
                 $(fields.append(&mut $name::headers());)+
                 fields
             }
+
+            fn header_keys() -> Vec<Cow<'static, str>> {
+                let mut fields = Vec::with_capacity(Self::LENGTH);
+                $(fields.append(&mut $name::header_keys());)+
+                fields
+            }
+
+            fn dynamic_fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+                #![allow(non_snake_case)]
+                let ($($name,)+) = self;
+                let mut fields = Vec::new();
+                $(fields.append(&mut $name.dynamic_fields());)+
+                fields
+            }
+
+            fn header_groups() -> Vec<(Cow<'static, str>, usize)> {
+                // columns of an element without groups are left ungrouped
+                let mut groups = Vec::new();
+                let mut has_groups = false;
+                $(
+                    let mut list = $name::header_groups();
+                    if list.is_empty() {
+                        if $name::LENGTH > 0 {
+                            groups.push((Cow::Borrowed(""), $name::LENGTH));
+                        }
+                    } else {
+                        has_groups = true;
+                        groups.append(&mut list);
+                    }
+                )+
+
+                if !has_groups {
+                    groups.clear();
+                }
+
+                groups
+            }
+
+            fn column_settings<R>(table: &mut Table<R>, mut offset: usize)
+            where
+                R: Records + RecordsMut<String>,
+            {
+                $(
+                    $name::column_settings(table, offset);
+                    offset += $name::LENGTH;
+                )+
+
+                let _ = offset;
+            }
         }
     };
 }
@@ -76,6 +356,12 @@ tuple_table! { A B C }
 tuple_table! { A B C D }
 tuple_table! { A B C D E }
 tuple_table! { A B C D E F }
+tuple_table! { A B C D E F G }
+tuple_table! { A B C D E F G H }
+tuple_table! { A B C D E F G H I }
+tuple_table! { A B C D E F G H I J }
+tuple_table! { A B C D E F G H I J K }
+tuple_table! { A B C D E F G H I J K L }
 
 macro_rules! default_table {
     ( $t:ty ) => {
@@ -85,6 +371,9 @@ macro_rules! default_table {
             fn fields(&self) -> Vec<Cow<'_, str>> {
                 vec![Cow::Owned(self.to_string())]
             }
+            fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn Display)) {
+                visitor(self)
+            }
             fn headers() -> Vec<Cow<'static, str>> {
                 vec![Cow::Borrowed(stringify!($t))]
             }
@@ -98,6 +387,9 @@ macro_rules! default_table {
             fn fields(&self) -> Vec<Cow<'_, str>> {
                 vec![Cow::Borrowed(self)]
             }
+            fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn Display)) {
+                visitor(&self)
+            }
             fn headers() -> Vec<Cow<'static, str>> {
                 vec![Cow::Borrowed(stringify!($t))]
             }
@@ -133,7 +425,7 @@ default_table!(f64);
 
 impl<T, const N: usize> Tabled for [T; N]
 where
-    T: std::fmt::Display,
+    T: Display,
 {
     const LENGTH: usize = N;
 
@@ -144,6 +436,12 @@ where
             .collect()
     }
 
+    fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn Display)) {
+        for value in self {
+            visitor(value);
+        }
+    }
+
     fn headers() -> Vec<Cow<'static, str>> {
         (0..N).map(|i| Cow::Owned(format!("{}", i))).collect()
     }
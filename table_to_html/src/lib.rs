@@ -122,6 +122,10 @@ pub struct HtmlTable<T = Table> {
     id: String,
     border_size: usize,
     unit: Unit,
+    indent: usize,
+    minify: bool,
+    fragment: bool,
+    line_mode: LineMode,
     custom_table_attributes: Vec<Attr<'static, String>>,
     custom_td_attributes: Vec<Attr<'static, String>>,
     custom_tr_attributes: Vec<Attr<'static, String>>,
@@ -149,6 +153,37 @@ impl<T> HtmlTable<T> {
         self.border_size = size;
     }
 
+    /// Set a number of spaces used to indent nested elements.
+    ///
+    /// Default value is `4`.
+    pub fn set_indent(&mut self, size: usize) {
+        self.indent = size;
+    }
+
+    /// Set whether the table must be printed in a single line,
+    /// without any indentation and new lines.
+    ///
+    /// It overrides an indentation set by [`HtmlTable::set_indent`].
+    pub fn set_minify(&mut self, minify: bool) {
+        self.minify = minify;
+    }
+
+    /// Set whether the `<table>` wrapper must be omitted,
+    /// so only `<thead>` and `<tbody>` elements are printed.
+    ///
+    /// It's useful when a table is being embedded into an existing `<table>` or a template.
+    /// Notice that attributes of a `<table>` such as an `id`, a border and a margin are not printed in the mode.
+    pub fn set_fragment(&mut self, fragment: bool) {
+        self.fragment = fragment;
+    }
+
+    /// Set a way multiline text is printed in a cell.
+    ///
+    /// Default value is [`LineMode::Paragraph`].
+    pub fn set_line_mode(&mut self, mode: LineMode) {
+        self.line_mode = mode;
+    }
+
     /// Adds an attribute to a `<table>`.
     pub fn add_table_attr(&mut self, key: impl Into<String>, value: impl Into<String>) {
         let key = key.into();
@@ -195,6 +230,10 @@ impl<R> From<Table<R>> for HtmlTable<Table<R>> {
             table,
             border_size: 1,
             unit: Unit::Rem,
+            indent: 4,
+            minify: false,
+            fragment: false,
+            line_mode: LineMode::Paragraph,
             custom_table_attributes: Vec::new(),
             custom_td_attributes: Vec::new(),
             custom_tr_attributes: Vec::new(),
@@ -209,6 +248,10 @@ impl fmt::Debug for HtmlTable {
             .field("id", &self.id)
             .field("border_size", &self.border_size)
             .field("unit", &self.unit)
+            .field("indent", &self.indent)
+            .field("minify", &self.minify)
+            .field("fragment", &self.fragment)
+            .field("line_mode", &self.line_mode)
             .field("custom_table_attributes", &self.custom_table_attributes)
             .field("custom_td_attributes", &self.custom_td_attributes)
             .field("custom_tr_attributes", &self.custom_tr_attributes)
@@ -229,17 +272,7 @@ where
     R: Records,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        convert_to_html_table(
-            f,
-            &self.table,
-            &self.id,
-            self.unit,
-            self.border_size,
-            &self.custom_table_attributes,
-            &self.custom_tr_attributes,
-            &self.custom_td_attributes,
-            &self.custom_cell_print,
-        )
+        convert_to_html_table(f, self)
     }
 }
 
@@ -254,6 +287,15 @@ pub enum Unit {
     Px,
 }
 
+/// LineMode represents a way a multiline text is emitted in a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LineMode {
+    /// Each line is wrapped in its own `<p></p>` element.
+    Paragraph,
+    /// Lines are joined by a `<br>` element.
+    Break,
+}
+
 impl From<Unit> for &'static str {
     fn from(val: Unit) -> Self {
         match val {
@@ -271,101 +313,71 @@ impl Display for Unit {
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn convert_to_html_table<R>(
-    f: &mut fmt::Formatter<'_>,
-    table: &Table<R>,
-    table_id: &str,
-    unit: Unit,
-    border_size: usize,
-    table_attrs: &[Attr<'static, String>],
-    tr_attrs: &[Attr<'static, String>],
-    td_attrs: &[Attr<'static, String>],
-    print_custom_cell: &Option<impl Fn(&Table<R>, usize, usize) -> String>,
-) -> fmt::Result
+fn convert_to_html_table<R>(f: &mut fmt::Formatter<'_>, html: &HtmlTable<Table<R>>) -> fmt::Result
 where
     R: Records,
 {
-    if table.has_header() {
-        let body = (0..2).map(|i| {
-            let (body_tag, inner_tag, (row_start, row_end)) = if i == 0 {
-                ("thead", "th", (0, 1))
-            } else {
-                ("tbody", "td", (1, table.count_rows()))
-            };
-
-            let rows = (row_start..row_end).map(move |row| {
-                let columns = (0..table.count_columns())
-                    .filter(move |col| {
-                        table
-                            .get_config()
-                            .is_cell_visible((row, *col), table.shape())
-                    })
-                    .map(move |col| {
-                        let td_attr = if body_tag == "tbody" { td_attrs } else { &[] };
-                        let attrs = create_cell_attrs(table, table_id, row, col, unit, td_attr);
-
-                        let text = match print_custom_cell.as_ref() {
-                            Some(f) => {
-                                let text = (f)(table, row, col);
-                                Paragraph::NoEdit(text)
-                            }
-                            None => {
-                                let text = table.get_records().get_text((row, col));
-                                let text = html_escape_text(text);
-                                Paragraph::General(text)
-                            }
-                        };
-
-                        tag(inner_tag, attrs, text)
-                    });
-
-                let td = block(columns);
-
-                let mut attrs = vec![attr("id", id(table_id, [row]).to_string())];
-                attrs.extend(tr_attrs.iter().cloned());
-
-                tag("tr", attrs, td)
-            });
-            let inner = block(rows);
-            tag(body_tag, [Attr::default(); 0], inner)
-        });
-
-        let mut attrs = vec![attr("id", table_id.to_string())];
+    let table = &html.table;
+    let (indent, new_line) = if html.minify {
+        (0, false)
+    } else {
+        (html.indent, true)
+    };
 
-        let margin = table.get_config().get_margin();
-        if *margin != Margin::default() {
-            let margin = format!(
-                "margin: {}{} {}{} {}{} {}{};",
-                margin.top.size,
-                unit,
-                margin.right.size,
-                unit,
-                margin.bottom.size,
-                unit,
-                margin.left.size,
-                unit
-            );
+    let mut ctx = Context::new(0, indent, new_line, f);
 
-            let attr = attr("style", margin);
-            attrs.push(attr);
-        }
+    if table.has_header() {
+        let head = build_table_section(html, "thead", "th", 0, 1, &[]);
+        let body = build_table_section(
+            html,
+            "tbody",
+            "td",
+            1,
+            table.count_rows(),
+            &html.custom_td_attributes,
+        );
+        let body = block([&head, &body]);
 
-        if border_size > 0 {
-            attrs.push(attr("border", border_size.to_string()));
+        if html.fragment {
+            return body.display(&mut ctx);
         }
 
-        attrs.extend(table_attrs.iter().cloned());
+        let attrs = create_table_attrs(html);
+        return tag("table", attrs, body).display(&mut ctx);
+    }
 
-        let table = tag("table", attrs, block(body));
+    let body = build_table_section(
+        html,
+        "tbody",
+        "td",
+        0,
+        table.count_rows(),
+        &html.custom_td_attributes,
+    );
+
+    if html.fragment {
+        return body.display(&mut ctx);
+    }
 
-        let mut ctx = Context::new(0, 4, f);
-        table.display(&mut ctx)?;
+    let attrs = create_table_attrs(html);
+    tag("table", attrs, body).display(&mut ctx)
+}
 
-        return Ok(());
-    }
+fn build_table_section<'a, R>(
+    html: &'a HtmlTable<Table<R>>,
+    section_tag: &'a str,
+    cell_tag: &'a str,
+    row_start: usize,
+    row_end: usize,
+    td_attrs: &'a [Attr<'static, String>],
+) -> impl Element + 'a
+where
+    R: Records,
+{
+    let table = &html.table;
+    let table_id = html.id.as_str();
 
-    let rows = (0..table.count_rows()).map(|row| {
+    let rows = (row_start..row_end).map(move |row| {
         let columns = (0..table.count_columns())
             .filter(move |col| {
                 table
@@ -373,9 +385,9 @@ where
                     .is_cell_visible((row, *col), table.shape())
             })
             .map(move |col| {
-                let attrs = create_cell_attrs(table, table_id, row, col, unit, td_attrs);
+                let attrs = create_cell_attrs(table, table_id, row, col, html.unit, td_attrs);
 
-                let text = match print_custom_cell.as_ref() {
+                let text = match html.custom_cell_print.as_ref() {
                     Some(f) => {
                         let text = (f)(table, row, col);
                         Paragraph::NoEdit(text)
@@ -383,26 +395,33 @@ where
                     None => {
                         let text = table.get_records().get_text((row, col));
                         let text = html_escape_text(text);
-                        Paragraph::General(text)
+                        match html.line_mode {
+                            LineMode::Paragraph => Paragraph::General(text),
+                            LineMode::Break => Paragraph::LineBreak(text),
+                        }
                     }
                 };
 
-                tag("td", attrs, text)
+                tag(cell_tag, attrs, text)
             });
 
         let td = block(columns);
 
         let mut attrs = vec![attr("id", id(table_id, [row]).to_string())];
-        attrs.extend(tr_attrs.iter().cloned());
+        attrs.extend(html.custom_tr_attributes.iter().cloned());
 
         tag("tr", attrs, td)
     });
-    let inner = block(rows);
-    let tbody = tag("tbody", [Attr::default(); 0], inner);
 
-    let mut attrs = vec![attr("id", table_id.to_string())];
+    tag(section_tag, Vec::<Attr<'_, &str>>::new(), block(rows))
+}
+
+fn create_table_attrs<R>(html: &HtmlTable<Table<R>>) -> Vec<Attr<'_, String>> {
+    let unit = html.unit;
+
+    let mut attrs = vec![attr("id", html.id.to_string())];
 
-    let margin = table.get_config().get_margin();
+    let margin = html.table.get_config().get_margin();
     if *margin != Margin::default() {
         let margin = format!(
             "margin: {}{} {}{} {}{} {}{};",
@@ -420,16 +439,13 @@ where
         attrs.push(attr);
     }
 
-    if border_size > 0 {
-        attrs.push(attr("border", border_size.to_string()));
+    if html.border_size > 0 {
+        attrs.push(attr("border", html.border_size.to_string()));
     }
 
-    attrs.extend(table_attrs.iter().cloned());
+    attrs.extend(html.custom_table_attributes.iter().cloned());
 
-    let table = tag("table", attrs, tbody);
-
-    let mut ctx = Context::new(0, 4, f);
-    table.display(&mut ctx)
+    attrs
 }
 
 fn create_cell_attrs<R>(
@@ -587,12 +603,6 @@ struct Attr<'a, D> {
     value: D,
 }
 
-impl Default for Attr<'static, &'static str> {
-    fn default() -> Self {
-        Self::new("", "")
-    }
-}
-
 impl<'a, D> Attr<'a, D> {
     fn new(name: impl Into<Cow<'a, str>>, value: D) -> Self {
         let name = name.into();
@@ -656,12 +666,13 @@ where
             attr.fmt(ctx.deref_mut())?;
         }
 
-        ctx.write_str(">\n")?;
+        ctx.write_char('>')?;
+        ctx.make_new_line()?;
 
         if !self.inner.is_empty() {
             let mut ctx = ctx.dive();
             self.inner.display(&mut ctx)?;
-            ctx.write_str("\n")?;
+            ctx.make_new_line()?;
         }
 
         ctx.make_tab()?;
@@ -677,12 +688,15 @@ where
     }
 }
 
-/// A type which represents either a text which will be emitted inside `<p></p>` tags or
+/// A type which represents either a text which will be emitted inside `<p></p>` tags,
+/// a text which lines will be joined by `<br>` or
 /// A custom HTML which will be left as it is.
 #[derive(Debug)]
 pub enum Paragraph {
     /// Text inside paragraphs.
     General(String),
+    /// Text which lines are separated by `<br>`.
+    LineBreak(String),
     /// HTML which will be emitted.
     NoEdit(String),
 }
@@ -693,7 +707,7 @@ impl Element for Paragraph {
             Paragraph::General(text) => {
                 for (i, line) in text.lines().enumerate() {
                     if i > 0 {
-                        ctx.write_str("\n")?;
+                        ctx.make_new_line()?;
                     }
 
                     ctx.make_tab()?;
@@ -702,6 +716,17 @@ impl Element for Paragraph {
                     ctx.write_str(" </p>")?;
                 }
             }
+            Paragraph::LineBreak(text) => {
+                for (i, line) in text.lines().enumerate() {
+                    if i > 0 {
+                        ctx.write_str("<br>")?;
+                        ctx.make_new_line()?;
+                    }
+
+                    ctx.make_tab()?;
+                    ctx.write_str(line)?;
+                }
+            }
             Paragraph::NoEdit(text) => text.display(ctx)?,
         }
 
@@ -711,11 +736,25 @@ impl Element for Paragraph {
     fn is_empty(&self) -> bool {
         match self {
             Paragraph::General(text) => text.is_empty(),
+            Paragraph::LineBreak(text) => text.is_empty(),
             Paragraph::NoEdit(text) => text.is_empty(),
         }
     }
 }
 
+impl<T> Element for &T
+where
+    T: Element + ?Sized,
+{
+    fn display(&self, ctx: &mut Context<'_, '_>) -> fmt::Result {
+        T::display(self, ctx)
+    }
+
+    fn is_empty(&self) -> bool {
+        T::is_empty(self)
+    }
+}
+
 impl Element for &str {
     fn display(&self, ctx: &mut Context<'_, '_>) -> fmt::Result {
         ctx.write_str(self)
@@ -762,7 +801,7 @@ where
     fn display(&self, ctx: &mut Context<'_, '_>) -> fmt::Result {
         for (i, element) in self.f.clone().into_iter().enumerate() {
             if i != 0 {
-                writeln!(ctx.f)?;
+                ctx.make_new_line()?;
             }
 
             element.display(ctx)?;
@@ -788,6 +827,7 @@ pub trait Element {
 pub struct Context<'a, 'b> {
     deep: usize,
     deep_step: usize,
+    new_line: bool,
     f: &'a mut fmt::Formatter<'b>,
 }
 
@@ -796,19 +836,30 @@ impl fmt::Debug for Context<'_, '_> {
         f.debug_struct("Context")
             .field("deep", &self.deep)
             .field("deep_step", &self.deep_step)
+            .field("new_line", &self.new_line)
             .field("f", &std::ptr::addr_of!(self.f))
             .finish()
     }
 }
 
 impl<'a, 'b> Context<'a, 'b> {
-    fn new(deep: usize, deep_step: usize, f: &'a mut fmt::Formatter<'b>) -> Self {
-        Self { deep, deep_step, f }
+    fn new(deep: usize, deep_step: usize, new_line: bool, f: &'a mut fmt::Formatter<'b>) -> Self {
+        Self {
+            deep,
+            deep_step,
+            new_line,
+            f,
+        }
     }
 
     /// Increase tab size
     pub fn dive<'c>(&'c mut self) -> Context<'c, 'b> {
-        Context::new(self.deep + self.deep_step, self.deep_step, self.f)
+        Context::new(
+            self.deep + self.deep_step,
+            self.deep_step,
+            self.new_line,
+            self.f,
+        )
     }
 }
 
@@ -821,6 +872,17 @@ impl Context<'_, '_> {
 
         Ok(())
     }
+
+    /// Print a new line.
+    ///
+    /// It does nothing in case the output is minified.
+    pub fn make_new_line(&mut self) -> fmt::Result {
+        if self.new_line {
+            self.write_char('\n')?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a, 'b> Deref for Context<'a, 'b> {
@@ -970,4 +1032,91 @@ mod tests {
 
         assert_eq!(table, "<table id=\"tabled-table\" border=\"1\">\n    <thead>\n        <tr id=\"tabled-table-0\">\n            <th id=\"tabled-table-0-0\">\n                <p> 0 </p>\n            </th>\n        </tr>\n    </thead>\n    <tbody>\n        <tr id=\"tabled-table-1\">\n            <td id=\"tabled-table-1-0\">\n                <p> &amp;&amp;&amp;&lt;&lt;&lt;&gt;&gt;&gt;&apos;&apos;&apos;&quot;&quot;&quot; </p>\n            </td>\n        </tr>\n    </tbody>\n</table>");
     }
+
+    #[test]
+    fn set_minify() {
+        let mut table = Table::new([["1\n2", "324"]]);
+        table.with(tabled::Padding::zero());
+        let mut table = HtmlTable::from(table);
+        table.set_minify(true);
+
+        let table = table.to_string();
+
+        assert_eq!(table, "<table id=\"tabled-table\" border=\"1\"><thead><tr id=\"tabled-table-0\"><th id=\"tabled-table-0-0\"><p> 0 </p></th><th id=\"tabled-table-0-1\"><p> 1 </p></th></tr></thead><tbody><tr id=\"tabled-table-1\"><td id=\"tabled-table-1-0\"><p> 1 </p><p> 2 </p></td><td id=\"tabled-table-1-1\"><p> 324 </p></td></tr></tbody></table>")
+    }
+
+    #[test]
+    fn set_indent() {
+        let mut table = Table::new([["123"]]);
+        table.with(tabled::Padding::zero());
+        let mut table = HtmlTable::from(table);
+        table.set_indent(1);
+
+        let table = table.to_string();
+
+        assert_eq!(table, "<table id=\"tabled-table\" border=\"1\">\n <thead>\n  <tr id=\"tabled-table-0\">\n   <th id=\"tabled-table-0-0\">\n    <p> 0 </p>\n   </th>\n  </tr>\n </thead>\n <tbody>\n  <tr id=\"tabled-table-1\">\n   <td id=\"tabled-table-1-0\">\n    <p> 123 </p>\n   </td>\n  </tr>\n </tbody>\n</table>")
+    }
+
+    #[test]
+    fn set_indent_zero() {
+        let mut table = Table::new([["123"]]);
+        table.with(tabled::Padding::zero());
+        let mut table = HtmlTable::from(table);
+        table.set_indent(0);
+
+        let table = table.to_string();
+
+        assert_eq!(table, "<table id=\"tabled-table\" border=\"1\">\n<thead>\n<tr id=\"tabled-table-0\">\n<th id=\"tabled-table-0-0\">\n<p> 0 </p>\n</th>\n</tr>\n</thead>\n<tbody>\n<tr id=\"tabled-table-1\">\n<td id=\"tabled-table-1-0\">\n<p> 123 </p>\n</td>\n</tr>\n</tbody>\n</table>")
+    }
+
+    #[test]
+    fn set_line_mode_break() {
+        let mut table = Table::new([["1\n2\n3"]]);
+        table.with(tabled::Padding::zero());
+        let mut table = HtmlTable::from(table);
+        table.set_line_mode(LineMode::Break);
+
+        let table = table.to_string();
+
+        assert_eq!(table, "<table id=\"tabled-table\" border=\"1\">\n    <thead>\n        <tr id=\"tabled-table-0\">\n            <th id=\"tabled-table-0-0\">\n                0\n            </th>\n        </tr>\n    </thead>\n    <tbody>\n        <tr id=\"tabled-table-1\">\n            <td id=\"tabled-table-1-0\">\n                1<br>\n                2<br>\n                3\n            </td>\n        </tr>\n    </tbody>\n</table>")
+    }
+
+    #[test]
+    fn set_line_mode_break_minify() {
+        let mut table = Table::new([["1\n2\n3"]]);
+        table.with(tabled::Padding::zero());
+        let mut table = HtmlTable::from(table);
+        table.set_line_mode(LineMode::Break);
+        table.set_minify(true);
+
+        let table = table.to_string();
+
+        assert_eq!(table, "<table id=\"tabled-table\" border=\"1\"><thead><tr id=\"tabled-table-0\"><th id=\"tabled-table-0-0\">0</th></tr></thead><tbody><tr id=\"tabled-table-1\"><td id=\"tabled-table-1-0\">1<br>2<br>3</td></tr></tbody></table>")
+    }
+
+    #[test]
+    fn set_fragment() {
+        let mut table = Table::new([["123"]]);
+        table.with(tabled::Padding::zero());
+        let mut table = HtmlTable::from(table);
+        table.add_table_attr("custom-attr", "custom-val");
+        table.set_fragment(true);
+
+        let table = table.to_string();
+
+        assert_eq!(table, "<thead>\n    <tr id=\"tabled-table-0\">\n        <th id=\"tabled-table-0-0\">\n            <p> 0 </p>\n        </th>\n    </tr>\n</thead>\n<tbody>\n    <tr id=\"tabled-table-1\">\n        <td id=\"tabled-table-1-0\">\n            <p> 123 </p>\n        </td>\n    </tr>\n</tbody>")
+    }
+
+    #[test]
+    fn set_fragment_without_header() {
+        let mut table = tabled::builder::Builder::from(vec![vec![String::from("123")]]).build();
+        table.with(tabled::Padding::zero());
+        let mut table = HtmlTable::from(table);
+        table.set_fragment(true);
+        table.set_minify(true);
+
+        let table = table.to_string();
+
+        assert_eq!(table, "<tbody><tr id=\"tabled-table-0\"><td id=\"tabled-table-0-0\"><p> 123 </p></td></tr></tbody>")
+    }
 }
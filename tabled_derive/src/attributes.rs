@@ -1,17 +1,35 @@
-use syn::{Attribute, LitInt};
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{Attribute, Ident, LitInt, LitStr};
 
 use crate::{casing_style::CasingStyle, error::Error, parse};
 
 #[derive(Debug, Default)]
 pub struct Attributes {
     pub is_ignored: bool,
+    pub transparent: bool,
     pub inline: bool,
     pub inline_prefix: Option<String>,
+    pub group: bool,
     pub rename: Option<String>,
+    pub rename_key: Option<String>,
     pub rename_all: Option<CasingStyle>,
     pub display_with: Option<String>,
     pub display_with_use_self: bool,
+    pub format: Option<TokenStream>,
+    pub format_with_args: Option<Vec<TokenStream>>,
+    pub display_option: Option<String>,
+    pub enum_display: Option<EnumDisplay>,
+    pub nested: bool,
+    pub nested_style: Option<Ident>,
+    pub join: Option<String>,
+    pub align: Option<Ident>,
+    pub max_width: Option<usize>,
+    pub truncate: Option<String>,
+    pub color: Option<Ident>,
     pub order: Option<usize>,
+    pub column_ids: bool,
+    pub flatten_map: bool,
 }
 
 impl Attributes {
@@ -40,6 +58,7 @@ impl Attributes {
                     self.is_ignored = true;
                 }
             }
+            parse::TabledAttrKind::Transparent(b) => self.transparent = b.value,
             parse::TabledAttrKind::Inline(b, prefix) => {
                 if b.value {
                     self.inline = true;
@@ -49,38 +68,117 @@ impl Attributes {
                     self.inline_prefix = Some(prefix.value());
                 }
             }
+            parse::TabledAttrKind::Group => self.group = true,
+            parse::TabledAttrKind::ColumnIds => self.column_ids = true,
+            parse::TabledAttrKind::FlattenMap => self.flatten_map = true,
             parse::TabledAttrKind::Rename(value) => self.rename = Some(value.value()),
+            parse::TabledAttrKind::RenameKey(value) => self.rename_key = Some(value.value()),
             parse::TabledAttrKind::RenameAll(lit) => {
                 self.rename_all = Some(CasingStyle::from_lit(&lit)?);
             }
             parse::TabledAttrKind::DisplayWith(path, use_self) => {
+                if self.format.is_some() {
+                    return Err(conflict_error(&attr.ident));
+                }
+
                 self.display_with = Some(path.value());
                 self.display_with_use_self = use_self;
             }
+            parse::TabledAttrKind::Format(format, args) => {
+                if self.display_with.is_some() {
+                    return Err(conflict_error(&attr.ident));
+                }
+
+                self.format = Some(format.into_token_stream());
+                self.format_with_args = args.map(|args| {
+                    args.iter()
+                        .map(|arg| respan_self(arg.into_token_stream()))
+                        .collect()
+                });
+            }
+            parse::TabledAttrKind::DisplayOption(value) => {
+                self.display_option = Some(value.value());
+            }
+            parse::TabledAttrKind::EnumDisplay(lit) => {
+                self.enum_display = Some(EnumDisplay::from_lit(&lit)?);
+            }
+            parse::TabledAttrKind::Nested(style) => {
+                self.nested = true;
+                if let Some(style) = style {
+                    self.nested_style = Some(parse_style(&style)?);
+                }
+            }
+            parse::TabledAttrKind::Join(lit) => self.join = Some(lit.value()),
+            parse::TabledAttrKind::Align(lit) => self.align = Some(parse_alignment(&lit)?),
+            parse::TabledAttrKind::MaxWidth(lit) => self.max_width = Some(lit_int_to_usize(&lit)?),
+            parse::TabledAttrKind::Truncate(lit) => self.truncate = Some(lit.value()),
+            parse::TabledAttrKind::Color(lit) => self.color = Some(parse_color(&lit)?),
             parse::TabledAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
         }
 
         Ok(())
     }
 
+    // a field with dynamic columns has no static ones
     pub fn is_ignored(&self) -> bool {
-        self.is_ignored
+        self.is_ignored || self.flatten_map
+    }
+
+    pub fn has_column_settings(&self) -> bool {
+        self.align.is_some()
+            || self.max_width.is_some()
+            || self.truncate.is_some()
+            || self.color.is_some()
     }
 }
 
 pub struct ObjectAttributes {
+    pub rename: Option<String>,
     pub rename_all: Option<CasingStyle>,
+    pub transparent: bool,
+    pub display_option: Option<String>,
+    pub enum_display: Option<EnumDisplay>,
+    pub column_ids: bool,
 }
 
 impl ObjectAttributes {
     pub fn parse(attrs: &[Attribute]) -> Result<Self, Error> {
         let attrs = Attributes::parse(attrs)?;
         Ok(Self {
+            rename: attrs.rename,
             rename_all: attrs.rename_all,
+            transparent: attrs.transparent,
+            display_option: attrs.display_option,
+            enum_display: attrs.enum_display,
+            column_ids: attrs.column_ids,
         })
     }
 }
 
+/// A way an enum is displayed,
+/// by default each variant is a separate column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumDisplay {
+    /// A single column with a name of a variant.
+    Name,
+    /// A column with a name of a variant and a column per each field name of all variants.
+    Union,
+}
+
+impl EnumDisplay {
+    fn from_lit(lit: &LitStr) -> Result<Self, Error> {
+        match lit.value().as_str() {
+            "name" => Ok(Self::Name),
+            "union" => Ok(Self::Union),
+            value => Err(Error::new(
+                format!("unexpected enum display mode: {:?}", value),
+                lit.span(),
+                Some("expected one of \"name\" or \"union\"".to_owned()),
+            )),
+        }
+    }
+}
+
 fn lit_int_to_usize(value: &LitInt) -> Result<usize, Error> {
     value.base10_parse::<usize>().map_err(|e| {
         Error::new(
@@ -90,3 +188,84 @@ fn lit_int_to_usize(value: &LitInt) -> Result<usize, Error> {
         )
     })
 }
+
+fn parse_alignment(lit: &LitStr) -> Result<Ident, Error> {
+    match lit.value().as_str() {
+        "left" | "right" | "center" => Ok(Ident::new(&lit.value(), lit.span())),
+        value => Err(Error::new(
+            format!("unexpected alignment: {:?}", value),
+            lit.span(),
+            Some("expected one of \"left\", \"right\" or \"center\"".to_owned()),
+        )),
+    }
+}
+
+// A style is a name of a `Style` constructor,
+// so an unknown one is reported by the compiler.
+fn parse_style(lit: &LitStr) -> Result<Ident, Error> {
+    let value = lit.value();
+    let mut ident = syn::parse_str::<Ident>(&value).map_err(|_| {
+        Error::new(
+            format!("unexpected style: {:?}", value),
+            lit.span(),
+            Some("expected a name of a `Style` constructor, like \"psql\"".to_owned()),
+        )
+    })?;
+    ident.set_span(lit.span());
+
+    Ok(ident)
+}
+
+// A color is a name of a `Color` constant in lower case,
+// where a foreground one may be used without a `fg_` prefix,
+// so an unknown one is reported by the compiler.
+fn parse_color(lit: &LitStr) -> Result<Ident, Error> {
+    let value = lit.value();
+    let name = value.to_uppercase();
+    let name = if name.starts_with("FG_") || name.starts_with("BG_") {
+        name
+    } else {
+        format!("FG_{}", name)
+    };
+
+    let mut ident = syn::parse_str::<Ident>(&name).map_err(|_| {
+        Error::new(
+            format!("unexpected color: {:?}", value),
+            lit.span(),
+            Some(
+                "expected a name of a `Color` constant, like \"red\" or \"bg_bright_blue\""
+                    .to_owned(),
+            ),
+        )
+    })?;
+    ident.set_span(lit.span());
+
+    Ok(ident)
+}
+
+fn conflict_error(attr: &syn::Ident) -> Error {
+    Error::new(
+        "`format` and `display_with` can't be used together",
+        attr.span(),
+        None,
+    )
+}
+
+// `self` must have the same span as the one in a generated method,
+// otherwise it can't be resolved when a type is declared by a macro.
+fn respan_self(tokens: TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) if ident == "self" => {
+                TokenTree::Ident(syn::Ident::new("self", Span::call_site()))
+            }
+            TokenTree::Group(group) => {
+                let mut new = Group::new(group.delimiter(), respan_self(group.stream()));
+                new.set_span(group.span());
+                TokenTree::Group(new)
+            }
+            token => token,
+        })
+        .collect()
+}
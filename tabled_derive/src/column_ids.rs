@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields};
+
+use crate::{
+    attributes::ObjectAttributes, error::Error, field_attributes, inline_type, reorder_fields,
+};
+
+// Generates a `<Name>Columns` struct with a column of each field,
+// and a `COLUMNS` constant of it.
+pub fn impl_column_ids(ast: &DeriveInput, attrs: &ObjectAttributes) -> Result<TokenStream, Error> {
+    let fields = match &ast.data {
+        Data::Struct(data) if matches!(data.fields, Fields::Named(_)) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                "`column_ids` can be used only with a struct with named fields",
+                ast.ident.span(),
+                None,
+            ))
+        }
+    };
+
+    let mut columns = Vec::new();
+    let mut reorder = HashMap::new();
+    let mut skipped = 0;
+    for (i, field) in fields.iter().enumerate() {
+        let attributes = field_attributes(field, attrs)?;
+        if attributes.is_ignored() {
+            skipped += 1;
+            continue;
+        }
+
+        if let Some(order) = attributes.order {
+            reorder.insert(order, i - skipped);
+        }
+
+        let length = if attributes.inline {
+            let field_type = inline_type(field, &attributes);
+            Some(quote!(<#field_type as Tabled>::LENGTH))
+        } else {
+            None
+        };
+
+        columns.push((field.ident.clone(), length));
+    }
+
+    if !reorder.is_empty() {
+        columns = reorder_fields(&reorder, &columns);
+    }
+
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let columns_name = format_ident!("{}Columns", name);
+
+    let mut offset = quote!(0);
+    let mut struct_fields = Vec::new();
+    let mut values = Vec::new();
+    for (ident, length) in columns {
+        let doc = format!("A column of `{}` field.", ident.as_ref().unwrap());
+        match length {
+            Some(length) => {
+                struct_fields.push(quote! {
+                    #[doc = #doc]
+                    #vis #ident: ::tabled::object::Columns<::std::ops::Range<usize>>
+                });
+                values.push(quote! {
+                    #ident: ::tabled::object::Columns::new(#offset..#offset + #length)
+                });
+                offset = quote!(#offset + #length);
+            }
+            None => {
+                struct_fields.push(quote! {
+                    #[doc = #doc]
+                    #vis #ident: ::tabled::object::Column
+                });
+                values.push(quote! {
+                    #ident: ::tabled::object::Columns::single(#offset)
+                });
+                offset = quote!(#offset + 1);
+            }
+        }
+    }
+
+    let doc = format!("Columns of a table built from [`{}`].", name);
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let expanded = quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone)]
+        #vis struct #columns_name {
+            #(#struct_fields,)*
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Columns of a table built from the type.
+            #vis const COLUMNS: #columns_name = #columns_name {
+                #(#values,)*
+            };
+        }
+    };
+
+    Ok(expanded)
+}
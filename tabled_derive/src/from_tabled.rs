@@ -0,0 +1,148 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Fields, Type};
+
+use crate::{
+    attributes::{Attributes, ObjectAttributes},
+    error::Error,
+    field_attributes, field_header_name, option_inner_type,
+};
+
+pub fn impl_from_tabled(ast: &DeriveInput) -> Result<TokenStream, Error> {
+    let attrs = ObjectAttributes::parse(&ast.attrs)?;
+
+    let fields = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                "`FromTabled` can be derived only for a struct",
+                ast.ident.span(),
+                None,
+            ))
+        }
+    };
+
+    let mut values = Vec::new();
+    let mut column_values = Vec::new();
+    let mut count_fields = 0;
+    let mut has_inline = false;
+    for (i, field) in fields.iter().enumerate() {
+        let attributes = field_attributes(field, &attrs)?;
+        values.push(field_value(field, i, &attributes, attrs.transparent));
+        column_values.push(field_column_value(field, &attributes, attrs.transparent));
+
+        if !attributes.is_ignored() {
+            count_fields += 1;
+            has_inline |= attributes.inline;
+        }
+    }
+
+    let value = build_value(fields, &values);
+
+    // a type with a single column could be displayed under a name of a field it's inlined as,
+    // see `INLINE_AS_FIELD`.
+    let column_value = build_value(fields, &column_values);
+    let is_single_column = count_fields == 1 && !has_inline;
+    let from_column = if is_single_column || attrs.transparent {
+        quote! {
+            #[allow(unused_variables)]
+            fn from_column(row: &::tabled::TabledRow<'_>, column: &str) -> Result<Self, ::tabled::FromTabledError> {
+                Ok(#column_value)
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let from_inlined_row = if attrs.transparent {
+        quote! {
+            fn from_inlined_row(row: &::tabled::TabledRow<'_>, field: &str) -> Result<Self, ::tabled::FromTabledError> {
+                Self::from_column(row, field)
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let expanded = quote! {
+        impl #impl_generics FromTabled for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn from_row(row: &::tabled::TabledRow<'_>) -> Result<Self, ::tabled::FromTabledError> {
+                Ok(#value)
+            }
+
+            #from_column
+
+            #from_inlined_row
+        }
+    };
+
+    Ok(expanded)
+}
+
+fn build_value(fields: &Fields, values: &[TokenStream]) -> TokenStream {
+    match fields {
+        Fields::Named(_) => {
+            let names = fields.iter().map(|field| &field.ident);
+            quote!(Self { #(#names: #values,)* })
+        }
+        Fields::Unnamed(_) => quote!(Self(#(#values,)*)),
+        Fields::Unit => quote!(Self),
+    }
+}
+
+// A value of a field in case the type is displayed as a single column named `column`.
+fn field_column_value(field: &Field, attr: &Attributes, transparent: bool) -> TokenStream {
+    if attr.is_ignored() {
+        return quote!(::std::default::Default::default());
+    }
+
+    let field_type = &field.ty;
+
+    if transparent {
+        return quote!(<#field_type as FromTabled>::from_column(row, column)?);
+    }
+
+    parse_cell(field_type, attr, quote!(column))
+}
+
+// `None` is an empty cell unless a placeholder is set, same as `Tabled` displays it.
+fn parse_cell(field_type: &Type, attr: &Attributes, column: TokenStream) -> TokenStream {
+    if option_inner_type(field_type).is_none() {
+        return quote!(row.parse(#column)?);
+    }
+
+    let placeholder = attr.display_option.as_deref().unwrap_or("");
+    quote!(row.parse_option(#column, #placeholder)?)
+}
+
+fn field_value(field: &Field, index: usize, attr: &Attributes, transparent: bool) -> TokenStream {
+    if attr.is_ignored() {
+        return quote!(::std::default::Default::default());
+    }
+
+    let field_type = &field.ty;
+
+    // a transparent type has the same columns as its only field
+    if transparent {
+        return quote!(<#field_type as FromTabled>::from_row(row)?);
+    }
+
+    let header = field_header_name(field, attr, index);
+
+    if attr.inline {
+        let prefix = attr.inline_prefix.as_deref().unwrap_or("");
+        return match (&attr.display_option, option_inner_type(field_type)) {
+            (Some(placeholder), Some(inner)) => quote!(
+                row.with_prefix(#prefix).parse_inlined_option::<#inner>(#header, #placeholder)?
+            ),
+            _ => quote!(
+                <#field_type as FromTabled>::from_inlined_row(&row.with_prefix(#prefix), #header)?
+            ),
+        };
+    }
+
+    parse_cell(field_type, attr, quote!(#header))
+}
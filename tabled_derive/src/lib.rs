@@ -4,7 +4,9 @@ extern crate proc_macro;
 
 mod attributes;
 mod casing_style;
+mod column_ids;
 mod error;
+mod from_tabled;
 mod parse;
 
 use proc_macro2::TokenStream;
@@ -12,11 +14,11 @@ use proc_macro_error::proc_macro_error;
 use quote::{quote, ToTokens, TokenStreamExt};
 use std::{collections::HashMap, str};
 use syn::{
-    parse_macro_input, token, Data, DataEnum, DataStruct, DeriveInput, Field, Fields, Ident, Index,
-    Type, Variant,
+    parse_macro_input, spanned::Spanned, token, Data, DataEnum, DataStruct, DeriveInput, Field,
+    Fields, GenericArgument, Ident, Index, PathArguments, Type, Variant,
 };
 
-use attributes::{Attributes, ObjectAttributes};
+use attributes::{Attributes, EnumDisplay, ObjectAttributes};
 use error::Error;
 
 #[proc_macro_derive(Tabled, attributes(tabled))]
@@ -27,15 +29,115 @@ pub fn tabled(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     proc_macro::TokenStream::from(ast)
 }
 
+#[proc_macro_derive(FromTabled, attributes(tabled))]
+#[proc_macro_error]
+pub fn from_tabled(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ast = from_tabled::impl_from_tabled(&input)
+        .map_err(error::abort)
+        .unwrap();
+    proc_macro::TokenStream::from(ast)
+}
+
 fn impl_tabled(ast: &DeriveInput) -> TokenStream {
     let attrs = ObjectAttributes::parse(&ast.attrs)
         .map_err(error::abort)
         .unwrap();
 
-    let length = get_tabled_length(ast).map_err(error::abort).unwrap();
+    // `column_ids` describes columns of struct fields,
+    // so it's rejected before a type is displayed any other way.
+    if attrs.column_ids {
+        let conflict = if attrs.transparent {
+            Some("transparent")
+        } else if attrs.enum_display.is_some() {
+            Some("enum_display")
+        } else {
+            None
+        };
+
+        if let Some(conflict) = conflict {
+            error::abort(Error::new(
+                format!("`column_ids` can't be used together with `{}`", conflict),
+                ast.ident.span(),
+                None,
+            ));
+        }
+    }
+
+    if attrs.transparent {
+        return impl_transparent(ast).map_err(error::abort).unwrap();
+    }
+
+    if let Some(mode) = attrs.enum_display {
+        return impl_enum_display(ast, &attrs, mode)
+            .map_err(error::abort)
+            .unwrap();
+    }
+
+    let column_ids = if attrs.column_ids {
+        column_ids::impl_column_ids(ast, &attrs)
+            .map_err(error::abort)
+            .unwrap()
+    } else {
+        TokenStream::new()
+    };
+
+    let length = get_tabled_length(ast, &attrs)
+        .map_err(error::abort)
+        .unwrap();
     let info = collect_info(ast, &attrs).map_err(error::abort).unwrap();
     let fields = info.values;
+    let visit = info.visit;
     let headers = info.headers;
+    let settings = if info.has_settings {
+        let settings = info.settings;
+        quote! {
+            #[allow(unused_assignments)]
+            fn column_settings<TabledRecords>(table: &mut ::tabled::Table<TabledRecords>, offset: usize)
+            where
+                TabledRecords: ::tabled::papergrid::records::Records
+                    + ::tabled::papergrid::records::RecordsMut<::std::string::String>,
+            {
+                let mut offset = offset;
+                #settings
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let keys = if info.has_keys {
+        let keys = info.keys;
+        quote! {
+            fn header_keys() -> Vec<::std::borrow::Cow<'static, str>> {
+                #keys
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let dynamic = if info.has_dynamic {
+        let dynamic = info.dynamic;
+        quote! {
+            fn dynamic_fields(&self) -> Vec<(::std::borrow::Cow<'_, str>, ::std::borrow::Cow<'_, str>)> {
+                #dynamic
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let groups = if info.has_groups {
+        let groups = info.groups;
+        quote! {
+            fn header_groups() -> Vec<(::std::borrow::Cow<'static, str>, usize)> {
+                #groups
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
 
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
@@ -47,28 +149,44 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
                 #fields
             }
 
+            fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn ::std::fmt::Display)) {
+                #visit
+            }
+
             fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
                 #headers
             }
+
+            #keys
+
+            #groups
+
+            #dynamic
+
+            #settings
         }
     };
 
-    expanded
+    quote! {
+        #expanded
+        #column_ids
+    }
 }
 
-fn get_tabled_length(ast: &DeriveInput) -> Result<TokenStream, Error> {
+fn get_tabled_length(ast: &DeriveInput, attrs: &ObjectAttributes) -> Result<TokenStream, Error> {
     match &ast.data {
-        Data::Struct(data) => get_fields_length(&data.fields),
-        Data::Enum(data) => get_enum_length(data),
+        Data::Struct(data) if matches!(data.fields, Fields::Unit) => Ok(quote!(1)),
+        Data::Struct(data) => get_fields_length(&data.fields, attrs),
+        Data::Enum(data) => get_enum_length(data, attrs),
         Data::Union(_) => Err(Error::message("Union type isn't supported")),
     }
 }
 
-fn get_fields_length(fields: &Fields) -> Result<TokenStream, Error> {
+fn get_fields_length(fields: &Fields, attrs: &ObjectAttributes) -> Result<TokenStream, Error> {
     let size_components = fields
         .iter()
         .map(|field| {
-            let attributes = Attributes::parse(&field.attrs)?;
+            let attributes = field_attributes(field, attrs)?;
             Ok((field, attributes))
         })
         .collect::<Result<Vec<_>, Error>>()?
@@ -76,7 +194,7 @@ fn get_fields_length(fields: &Fields) -> Result<TokenStream, Error> {
         .filter(|(_, attr)| !attr.is_ignored())
         .map(|(field, attr)| {
             if attr.inline {
-                let field_type = &field.ty;
+                let field_type = inline_type(field, &attr);
                 quote!({<#field_type as Tabled>::LENGTH})
             } else {
                 quote!({ 1 })
@@ -91,8 +209,8 @@ fn get_fields_length(fields: &Fields) -> Result<TokenStream, Error> {
     Ok(stream)
 }
 
-fn get_enum_length(enum_ast: &DataEnum) -> Result<TokenStream, Error> {
-    let variant_sizes = get_enum_variant_length(enum_ast);
+fn get_enum_length(enum_ast: &DataEnum, attrs: &ObjectAttributes) -> Result<TokenStream, Error> {
+    let variant_sizes = get_enum_variant_length(enum_ast, attrs);
 
     let mut stream = TokenStream::new();
     for (i, size) in variant_sizes.enumerate() {
@@ -108,9 +226,10 @@ fn get_enum_length(enum_ast: &DataEnum) -> Result<TokenStream, Error> {
     Ok(stream)
 }
 
-fn get_enum_variant_length(
-    enum_ast: &DataEnum,
-) -> impl Iterator<Item = Result<TokenStream, Error>> + '_ {
+fn get_enum_variant_length<'a>(
+    enum_ast: &'a DataEnum,
+    attrs: &'a ObjectAttributes,
+) -> impl Iterator<Item = Result<TokenStream, Error>> + 'a {
     enum_ast
         .variants
         .iter()
@@ -119,11 +238,11 @@ fn get_enum_variant_length(
             Ok((variant, attributes))
         })
         .filter(|result| result.is_err() || matches!(result, Ok((_, attr)) if !attr.is_ignored()))
-        .map(|result| {
+        .map(move |result| {
             let (variant, attr) = result?;
 
-            if attr.inline {
-                get_fields_length(&variant.fields)
+            if attr.inline && !matches!(variant.fields, Fields::Unit) {
+                get_fields_length(&variant.fields, attrs)
             } else {
                 Ok(quote!(1))
             }
@@ -132,6 +251,9 @@ fn get_enum_variant_length(
 
 fn collect_info(ast: &DeriveInput, attrs: &ObjectAttributes) -> Result<Impl, Error> {
     match &ast.data {
+        Data::Struct(data) if matches!(data.fields, Fields::Unit) => {
+            Ok(info_from_unit(&ast.ident, attrs))
+        }
         Data::Struct(data) => collect_info_struct(data, attrs),
         Data::Enum(data) => collect_info_enum(data, attrs),
         Data::Union(_) => Err(Error::message("Union type isn't supported")),
@@ -157,24 +279,50 @@ fn info_from_fields(
         .into_iter()
         .enumerate()
         .map(|(i, field)| -> Result<_, Error> {
-            let mut attributes = Attributes::parse(&field.attrs)?;
-            merge_attributes(&mut attributes, attrs);
-
+            let attributes = field_attributes(field, attrs)?;
             Ok((i, field, attributes))
         });
 
     let mut headers = Vec::new();
+    let mut keys = Vec::new();
+    let mut has_keys = false;
     let mut values = Vec::new();
+    let mut visits = Vec::new();
+    let mut settings = Vec::new();
+    let mut has_settings = false;
+    let mut groups = Vec::new();
+    let mut has_groups = false;
+    let mut dynamic = Vec::new();
+    let mut has_dynamic = false;
     let mut reorder = HashMap::new();
 
     let mut skipped = 0;
     for result in fields {
         let (i, field, attributes) = result?;
+        if attributes.flatten_map {
+            let field_name = field_name(i, field);
+            dynamic.push(quote! {
+                for (key, value) in #field_name.iter() {
+                    out.push((
+                        ::std::borrow::Cow::Owned(key.to_string()),
+                        ::std::borrow::Cow::Owned(value.to_string()),
+                    ));
+                }
+            });
+            has_dynamic = true;
+        }
+
         if attributes.is_ignored() {
             skipped += 1;
             continue;
         }
 
+        if attributes.inline {
+            let field_name = field_name(i, field);
+            dynamic.push(quote! { out.extend(#field_name.dynamic_fields()); });
+            has_dynamic = true;
+        }
+
         if let Some(order) = attributes.order {
             if order >= count_fields {
                 return Err(Error::message(format!(
@@ -189,14 +337,52 @@ fn info_from_fields(
         let header = field_headers(field, i, &attributes, header_prefix);
         headers.push(header);
 
+        let key = field_header_keys(field, i, &attributes, header_prefix);
+        keys.push(key);
+        has_keys |= attributes.inline || attributes.rename_key.is_some();
+
         let field_name = field_name(i, field);
-        let value = get_field_fields(&field_name, &attributes);
+        let value = get_field_fields(&field_name, inline_type(field, &attributes), &attributes);
         values.push(value);
+
+        let visit = get_field_visit(&field_name, inline_type(field, &attributes), &attributes);
+        visits.push(visit);
+
+        let columns = if attributes.inline {
+            let field_type = inline_type(field, &attributes);
+            quote! {
+                <#field_type as Tabled>::column_settings(table, offset);
+                offset += <#field_type as Tabled>::LENGTH;
+            }
+        } else {
+            quote! { offset += 1; }
+        };
+        settings.push(get_column_settings(columns, &attributes)?);
+        has_settings |= attributes.inline || attributes.has_column_settings();
+
+        let group = if attributes.inline {
+            let field_type = inline_type(field, &attributes);
+            let name = if attributes.group {
+                field_header_name(field, &attributes, i)
+            } else {
+                String::new()
+            };
+
+            quote!((::std::borrow::Cow::Borrowed(#name), <#field_type as Tabled>::LENGTH))
+        } else {
+            quote!((::std::borrow::Cow::Borrowed(""), 1))
+        };
+        groups.push(group);
+        has_groups |= attributes.group;
     }
 
     if !reorder.is_empty() {
         values = reorder_fields(&reorder, &values);
+        visits = reorder_fields(&reorder, &visits);
         headers = reorder_fields(&reorder, &headers);
+        keys = reorder_fields(&reorder, &keys);
+        settings = reorder_fields(&reorder, &settings);
+        groups = reorder_fields(&reorder, &groups);
     }
 
     let headers = quote!({
@@ -205,13 +391,42 @@ fn info_from_fields(
         out
     });
 
+    let keys = quote!({
+        let mut out = Vec::new();
+        #(out.extend(#keys);)*
+        out
+    });
+
     let values = quote!({
         let mut out = Vec::new();
         #(out.extend(#values);)*
         out
     });
 
-    Ok(Impl { headers, values })
+    let visit = quote!(#(#visits)*);
+
+    let dynamic = quote!({
+        let mut out = Vec::new();
+        #(#dynamic)*
+        out
+    });
+
+    let settings = quote!(#(#settings)*);
+    let groups = quote!(vec![#(#groups),*]);
+
+    Ok(Impl {
+        headers,
+        keys,
+        has_keys,
+        values,
+        visit,
+        settings,
+        has_settings,
+        groups,
+        has_groups,
+        dynamic,
+        has_dynamic,
+    })
 }
 
 fn reorder_fields<T: Clone>(order: &HashMap<usize, usize>, elements: &[T]) -> Vec<T> {
@@ -253,7 +468,10 @@ fn field_headers(
             .inline_prefix
             .as_ref()
             .map_or_else(|| "", |s| s.as_str());
-        return get_type_headers(&field.ty, prefix, "");
+        let field_type = inline_type(field, attributes);
+        let headers = get_type_headers(field_type, prefix, "");
+        let name = format!("{}{}", prefix, field_header_name(field, attributes, index));
+        return inline_as_field(field_type, headers, quote!(#name));
     }
 
     let header_name = field_header_name(field, attributes, index);
@@ -265,9 +483,40 @@ fn field_headers(
     }
 }
 
+fn field_header_keys(
+    field: &Field,
+    index: usize,
+    attributes: &Attributes,
+    prefix: &str,
+) -> TokenStream {
+    if attributes.inline {
+        let prefix = attributes
+            .inline_prefix
+            .as_ref()
+            .map_or_else(|| "", |s| s.as_str());
+        let field_type = inline_type(field, attributes);
+        let keys = get_type_header_keys(field_type, prefix, "");
+        let key = match &attributes.rename_key {
+            Some(key) => key.clone(),
+            None => format!("{}{}", prefix, field_header_name(field, attributes, index)),
+        };
+        return inline_as_field(field_type, keys, quote!(#key));
+    }
+
+    match &attributes.rename_key {
+        Some(key) => quote!(vec![::std::borrow::Cow::Borrowed(#key)]),
+        None => field_headers(field, index, attributes, prefix),
+    }
+}
+
 fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, Error> {
     let mut headers_list = Vec::new();
+    let mut visits = Vec::new();
+    let mut keys_list = Vec::new();
+    let mut has_keys = false;
     let mut variants = Vec::new();
+    let mut settings = Vec::new();
+    let mut has_settings = false;
     for variant in &ast.variants {
         let mut attributes = Attributes::parse(&variant.attrs)?;
         merge_attributes(&mut attributes, attrs);
@@ -275,15 +524,37 @@ fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, E
             continue;
         }
 
+        for field in &variant.fields {
+            if Attributes::parse(&field.attrs)?.flatten_map {
+                return Err(Error::new(
+                    "`flatten_map` isn't supported for enum variants",
+                    field.span(),
+                    None,
+                ));
+            }
+        }
+
         let info = info_from_variant(variant, &attributes, attrs)?;
+        if info.has_groups {
+            return Err(Error::new(
+                "`group` isn't supported for enum variants",
+                variant.span(),
+                None,
+            ));
+        }
+
         variants.push((variant, info.values));
+        visits.push((variant, info.visit));
         headers_list.push(info.headers);
+        keys_list.push(info.keys);
+        has_keys |= info.has_keys;
+        settings.push(get_column_settings(info.settings, &attributes)?);
+        has_settings |= info.has_settings || attributes.has_column_settings();
     }
 
-    let variant_sizes = get_enum_variant_length(ast)
-        .collect::<Result<Vec<_>, Error>>()?
-        .into_iter();
-    let values = values_for_enum(variant_sizes, &variants);
+    let variant_sizes = get_enum_variant_length(ast, attrs).collect::<Result<Vec<_>, Error>>()?;
+    let values = values_for_enum(variant_sizes.iter().cloned(), &variants);
+    let visit = visit_for_enum(variant_sizes.into_iter(), &visits);
 
     let headers = quote! {
         vec![
@@ -292,7 +563,28 @@ fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, E
         .concat()
     };
 
-    Ok(Impl { headers, values })
+    let keys = quote! {
+        vec![
+            #(#keys_list,)*
+        ]
+        .concat()
+    };
+
+    let settings = quote!(#(#settings)*);
+
+    Ok(Impl {
+        headers,
+        keys,
+        has_keys,
+        values,
+        visit,
+        settings,
+        has_settings,
+        groups: TokenStream::new(),
+        has_groups: false,
+        dynamic: TokenStream::new(),
+        has_dynamic: false,
+    })
 }
 
 fn info_from_variant(
@@ -300,7 +592,8 @@ fn info_from_variant(
     attributes: &Attributes,
     attrs: &ObjectAttributes,
 ) -> Result<Impl, Error> {
-    if attributes.inline {
+    // a unit variant has nothing to inline so it's displayed as a regular one
+    if attributes.inline && !matches!(variant.fields, Fields::Unit) {
         let prefix = attributes
             .inline_prefix
             .as_ref()
@@ -313,23 +606,436 @@ fn info_from_variant(
 
     // we need exactly string because of it must be inlined as string
     let headers = quote! { vec![::std::borrow::Cow::Borrowed(#variant_name)] };
+    let key = attributes.rename_key.as_ref().unwrap_or(&variant_name);
+    let keys = quote! { vec![::std::borrow::Cow::Borrowed(#key)] };
     // we need exactly string because of it must be inlined as string
     let values = quote! { vec![::std::borrow::Cow::Borrowed(#value)] };
+    let visit = quote! { visitor(&#value); };
+
+    let settings = quote! { offset += 1; };
+
+    Ok(Impl {
+        headers,
+        keys,
+        has_keys: attributes.rename_key.is_some(),
+        values,
+        visit,
+        settings,
+        has_settings: false,
+        groups: TokenStream::new(),
+        has_groups: false,
+        dynamic: TokenStream::new(),
+        has_dynamic: false,
+    })
+}
+
+// A unit struct is displayed as a single column, like a unit variant.
+fn info_from_unit(name: &Ident, attrs: &ObjectAttributes) -> Impl {
+    let header = attrs
+        .rename
+        .clone()
+        .unwrap_or_else(|| match &attrs.rename_all {
+            Some(case) => case.cast(name.to_string()),
+            None => name.to_string(),
+        });
+    let value = "+";
+
+    Impl {
+        headers: quote! { vec![::std::borrow::Cow::Borrowed(#header)] },
+        keys: TokenStream::new(),
+        has_keys: false,
+        values: quote! { vec![::std::borrow::Cow::Borrowed(#value)] },
+        visit: quote! { visitor(&#value); },
+        settings: quote! { offset += 1; },
+        has_settings: false,
+        groups: TokenStream::new(),
+        has_groups: false,
+        dynamic: TokenStream::new(),
+        has_dynamic: false,
+    }
+}
+
+// A type with a single field which is displayed as the field itself.
+fn impl_transparent(ast: &DeriveInput) -> Result<TokenStream, Error> {
+    let error = || {
+        Error::new(
+            "`transparent` can be used only with a struct which has exactly 1 field",
+            ast.ident.span(),
+            Some("use `#[tabled(skip)]` to ignore other fields".to_owned()),
+        )
+    };
+
+    let fields = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(error()),
+    };
+
+    let mut found = None;
+    for (i, field) in fields.iter().enumerate() {
+        if Attributes::parse(&field.attrs)?.is_ignored() {
+            continue;
+        }
+
+        if found.is_some() {
+            return Err(error());
+        }
+
+        found = Some((i, field));
+    }
+
+    let (i, field) = found.ok_or_else(error)?;
+    let field_name = field_var_name(i, field);
+    let field_type = &field.ty;
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let expanded = quote! {
+        impl #impl_generics Tabled for #name #ty_generics #where_clause {
+            const LENGTH: usize = <#field_type as Tabled>::LENGTH;
+            const INLINE_AS_FIELD: bool = <#field_type as Tabled>::LENGTH == 1;
+
+            fn fields(&self) -> Vec<::std::borrow::Cow<'_, str>> {
+                <#field_type as Tabled>::fields(&#field_name)
+            }
+
+            fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn ::std::fmt::Display)) {
+                <#field_type as Tabled>::visit_fields(&#field_name, visitor)
+            }
+
+            fn dynamic_fields(&self) -> Vec<(::std::borrow::Cow<'_, str>, ::std::borrow::Cow<'_, str>)> {
+                <#field_type as Tabled>::dynamic_fields(&#field_name)
+            }
+
+            fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
+                <#field_type as Tabled>::headers()
+            }
+
+            fn header_keys() -> Vec<::std::borrow::Cow<'static, str>> {
+                <#field_type as Tabled>::header_keys()
+            }
+
+            fn header_groups() -> Vec<(::std::borrow::Cow<'static, str>, usize)> {
+                <#field_type as Tabled>::header_groups()
+            }
+
+            fn column_settings<TabledRecords>(table: &mut ::tabled::Table<TabledRecords>, offset: usize)
+            where
+                TabledRecords: ::tabled::papergrid::records::Records
+                    + ::tabled::papergrid::records::RecordsMut<::std::string::String>,
+            {
+                <#field_type as Tabled>::column_settings(table, offset)
+            }
+        }
+    };
+
+    Ok(expanded)
+}
+
+// An enum which is displayed as a single column of variant names,
+// optionally followed by a union of fields of all variants.
+fn impl_enum_display(
+    ast: &DeriveInput,
+    attrs: &ObjectAttributes,
+    mode: EnumDisplay,
+) -> Result<TokenStream, Error> {
+    let data = match &ast.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new(
+                "`enum_display` can be used only with an enum",
+                ast.ident.span(),
+                None,
+            ))
+        }
+    };
+
+    let mut columns: Vec<String> = Vec::new();
+    let mut keys: Vec<Option<String>> = Vec::new();
+    let mut settings = Vec::new();
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        let mut attributes = Attributes::parse(&variant.attrs)?;
+        merge_attributes(&mut attributes, attrs);
+        if attributes.is_ignored() {
+            continue;
+        }
+
+        // the name column is shared by all variants
+        if attributes.rename_key.is_some() || attributes.has_column_settings() {
+            return Err(Error::new(
+                "`rename_key` and column settings can't be set on a variant together with `enum_display`",
+                variant.span(),
+                None,
+            ));
+        }
+
+        let mut fields = Vec::new();
+        if mode == EnumDisplay::Union {
+            for (i, field) in variant.fields.iter().enumerate() {
+                let attributes = field_attributes(field, attrs)?;
+                if attributes.is_ignored() {
+                    continue;
+                }
+
+                if attributes.inline {
+                    return Err(Error::new(
+                        "`inline` can't be used together with `enum_display = \"union\"`",
+                        field.span(),
+                        None,
+                    ));
+                }
+
+                let header = field_header_name(field, &attributes, i);
+                let index = match columns.iter().position(|column| *column == header) {
+                    Some(index) => index,
+                    None => {
+                        columns.push(header.clone());
+                        keys.push(None);
+                        columns.len() - 1
+                    }
+                };
+
+                if let Some(key) = &attributes.rename_key {
+                    match &keys[index] {
+                        Some(other) if other != key => {
+                            return Err(Error::new(
+                                format!(
+                                    "fields of a column {:?} have different `rename_key`",
+                                    header
+                                ),
+                                field.span(),
+                                None,
+                            ))
+                        }
+                        _ => keys[index] = Some(key.clone()),
+                    }
+                }
+
+                let index = index + 1;
+                if attributes.has_column_settings() {
+                    let columns = get_column_settings(quote! { offset += 1; }, &attributes)?;
+                    settings.push(quote!({
+                        let mut offset = offset + #index;
+                        #columns
+                    }));
+                }
+
+                fields.push((index, variant_var_name(i, field), field, attributes));
+            }
+        }
+
+        let name = variant_name(variant, &attributes);
+        variants.push((variant, name, fields));
+    }
+
+    let length = columns.len() + 1;
+
+    let mut branches = Vec::new();
+    let mut visits = Vec::new();
+    for (variant, name, fields) in &variants {
+        let mut values = vec![quote! { out[0] = ::std::borrow::Cow::Borrowed(#name); }];
+        for (index, field_name, field, attributes) in fields {
+            let value = get_field_fields(field_name, &field.ty, attributes);
+            values.push(quote! {
+                if let Some(field) = (#value).into_iter().next() {
+                    out[#index] = field;
+                }
+            });
+        }
+
+        // a later field of the same column overrides a former one as it does in `fields`
+        let mut visit = vec![quote! { visitor(&#name); }];
+        for column in 1..length {
+            let field = fields.iter().rev().find(|(index, ..)| *index == column);
+            visit.push(match field {
+                Some((_, field_name, field, attributes)) => {
+                    get_field_visit(field_name, &field.ty, attributes)
+                }
+                None => quote! { visitor(&""); },
+            });
+        }
+
+        let branch = match_variant(variant);
+        branches.push(quote! { Self::#branch => { #(#values)* } });
+        visits.push(quote! { Self::#branch => { #(#visit)* } });
+    }
+
+    let header = attrs
+        .rename
+        .clone()
+        .unwrap_or_else(|| ast.ident.to_string());
+
+    let keys = if keys.iter().any(Option::is_some) {
+        let keys = keys
+            .iter()
+            .zip(&columns)
+            .map(|(key, column)| key.as_ref().unwrap_or(column));
+        quote! {
+            fn header_keys() -> Vec<::std::borrow::Cow<'static, str>> {
+                vec![
+                    ::std::borrow::Cow::Borrowed(#header),
+                    #(::std::borrow::Cow::Borrowed(#keys),)*
+                ]
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let settings = if settings.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! {
+            fn column_settings<TabledRecords>(table: &mut ::tabled::Table<TabledRecords>, offset: usize)
+            where
+                TabledRecords: ::tabled::papergrid::records::Records
+                    + ::tabled::papergrid::records::RecordsMut<::std::string::String>,
+            {
+                #(#settings)*
+            }
+        }
+    };
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let expanded = quote! {
+        impl #impl_generics Tabled for #name #ty_generics #where_clause {
+            const LENGTH: usize = #length;
+
+            fn fields(&self) -> Vec<::std::borrow::Cow<'_, str>> {
+                let mut out = vec![::std::borrow::Cow::Borrowed(""); #length];
+
+                #[allow(unused_variables)]
+                match &self {
+                    #(#branches)*
+                    _ => {} // variant is hidden so its cells are empty
+                };
+
+                out
+            }
+
+            fn visit_fields(&self, visitor: &mut dyn FnMut(&dyn ::std::fmt::Display)) {
+                #[allow(unused_variables)]
+                match &self {
+                    #(#visits)*
+                    _ => {
+                        // variant is hidden so its cells are empty
+                        for _ in 0..#length {
+                            visitor(&"");
+                        }
+                    }
+                };
+            }
 
-    Ok(Impl { headers, values })
+            fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
+                vec![
+                    ::std::borrow::Cow::Borrowed(#header),
+                    #(::std::borrow::Cow::Borrowed(#columns),)*
+                ]
+            }
+
+            #keys
+
+            #settings
+        }
+    };
+
+    Ok(expanded)
 }
 
 struct Impl {
     headers: TokenStream,
+    // keys of headers which are used for a localization.
+    keys: TokenStream,
+    has_keys: bool,
     values: TokenStream,
+    // a code which calls a `visitor` for each field, in the same order as `values`.
+    visit: TokenStream,
+    // a code which configures columns starting from an `offset`,
+    // it must move the `offset` by a number of the columns.
+    settings: TokenStream,
+    has_settings: bool,
+    // a list of `(name, count columns)` pairs for a header groups row.
+    groups: TokenStream,
+    has_groups: bool,
+    // a list of `(header, value)` pairs of dynamic columns.
+    dynamic: TokenStream,
+    has_dynamic: bool,
+}
+
+// Wraps a code which moves an `offset` over columns,
+// so the settings from the attributes are applied to these columns.
+fn get_column_settings(columns: TokenStream, attr: &Attributes) -> Result<TokenStream, Error> {
+    if !attr.has_column_settings() {
+        return Ok(columns);
+    }
+
+    let mut settings = Vec::new();
+
+    if let Some(align) = &attr.align {
+        settings.push(quote!(::tabled::Alignment::#align()));
+    }
+
+    match (attr.max_width, &attr.truncate) {
+        (Some(width), Some(suffix)) => {
+            settings.push(quote!(::tabled::Width::truncate(#width).suffix(#suffix)));
+        }
+        (Some(width), None) => settings.push(quote!(::tabled::Width::truncate(#width))),
+        (None, Some(_)) => {
+            return Err(Error::message(
+                "`truncate` attribute must be used together with `max_width`",
+            ))
+        }
+        (None, None) => {}
+    }
+
+    if let Some(color) = &attr.color {
+        settings.push(quote!(::tabled::__derive_color!(#color)));
+    }
+
+    Ok(quote! {
+        let start = offset;
+        #columns
+        table.with(
+            ::tabled::Modify::new(::tabled::object::Columns::new(start..offset))
+                #(.with(#settings))*
+        );
+    })
+}
+
+// A transparent newtype over a single column is inlined as a column named after the field.
+fn inline_as_field(field_type: &Type, headers: TokenStream, name: TokenStream) -> TokenStream {
+    quote! {
+        if <#field_type as Tabled>::INLINE_AS_FIELD {
+            vec![::std::borrow::Cow::Borrowed(#name)]
+        } else {
+            #headers
+        }
+    }
 }
 
 fn get_type_headers(field_type: &Type, inline_prefix: &str, prefix: &str) -> TokenStream {
+    prefix_headers(
+        quote!(<#field_type as Tabled>::headers()),
+        inline_prefix,
+        prefix,
+    )
+}
+
+fn get_type_header_keys(field_type: &Type, inline_prefix: &str, prefix: &str) -> TokenStream {
+    prefix_headers(
+        quote!(<#field_type as Tabled>::header_keys()),
+        inline_prefix,
+        prefix,
+    )
+}
+
+fn prefix_headers(headers: TokenStream, inline_prefix: &str, prefix: &str) -> TokenStream {
     if prefix.is_empty() && inline_prefix.is_empty() {
-        quote! { <#field_type as Tabled>::headers() }
+        headers
     } else {
         quote! {
-            <#field_type as Tabled>::headers().into_iter()
+            #headers.into_iter()
                 .map(|header| {
                     let header = format!("{}{}{}", #prefix, #inline_prefix, header);
                     ::std::borrow::Cow::Owned(header)
@@ -339,11 +1045,44 @@ fn get_type_headers(field_type: &Type, inline_prefix: &str, prefix: &str) -> Tok
     }
 }
 
-fn get_field_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
+fn get_field_fields(field: &TokenStream, field_type: &Type, attr: &Attributes) -> TokenStream {
+    let is_explicit = attr.display_with.is_some() || attr.format_with_args.is_some();
+    if let (Some(placeholder), false) = (&attr.display_option, is_explicit) {
+        let value = quote!(value);
+        let some = get_value_fields(&value, attr);
+        let none = if attr.inline {
+            quote!(
+                vec![::std::borrow::Cow::Borrowed(#placeholder); <#field_type as Tabled>::LENGTH]
+            )
+        } else {
+            quote!(vec![::std::borrow::Cow::Borrowed(#placeholder)])
+        };
+
+        return quote! {
+            match &#field {
+                Some(#value) => #some,
+                None => #none,
+            }
+        };
+    }
+
+    get_value_fields(field, attr)
+}
+
+fn get_value_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
     if attr.inline {
         return quote! { #field.fields() };
     }
 
+    if let Some(format) = &attr.format {
+        let args = match &attr.format_with_args {
+            Some(args) => quote!(#(#args),*),
+            None => quote!(#field),
+        };
+
+        return quote!(vec![::std::borrow::Cow::Owned(format!(#format, #args))]);
+    }
+
     if let Some(func) = &attr.display_with {
         let func_call = match attr.display_with_use_self {
             true => use_function_with_self(func),
@@ -353,9 +1092,101 @@ fn get_field_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
         return quote!(vec![::std::borrow::Cow::from(#func_call)]);
     }
 
+    if attr.nested {
+        let style = attr
+            .nested_style
+            .as_ref()
+            .map(|style| quote!(table.with(::tabled::Style::#style());));
+
+        return quote!({
+            let mut table = ::tabled::Table::new(#field.iter());
+            #style
+            vec![::std::borrow::Cow::Owned(table.to_string())]
+        });
+    }
+
+    if let Some(sep) = &attr.join {
+        return quote!({
+            let list = #field.iter().map(|value| value.to_string()).collect::<Vec<_>>();
+            vec![::std::borrow::Cow::Owned(list.join(#sep))]
+        });
+    }
+
     quote!(vec![::std::borrow::Cow::Owned(format!("{}", #field))])
 }
 
+fn get_field_visit(field: &TokenStream, field_type: &Type, attr: &Attributes) -> TokenStream {
+    let is_explicit = attr.display_with.is_some() || attr.format_with_args.is_some();
+    if let (Some(placeholder), false) = (&attr.display_option, is_explicit) {
+        let value = quote!(value);
+        let some = get_value_visit(&value, attr);
+        let count = if attr.inline {
+            quote!(<#field_type as Tabled>::LENGTH)
+        } else {
+            quote!(1)
+        };
+
+        return quote! {
+            match &#field {
+                Some(#value) => { #some }
+                None => {
+                    for _ in 0..#count {
+                        visitor(&#placeholder);
+                    }
+                }
+            }
+        };
+    }
+
+    get_value_visit(field, attr)
+}
+
+fn get_value_visit(field: &TokenStream, attr: &Attributes) -> TokenStream {
+    if attr.inline {
+        return quote! { #field.visit_fields(visitor); };
+    }
+
+    if let Some(format) = &attr.format {
+        let args = match &attr.format_with_args {
+            Some(args) => quote!(#(#args),*),
+            None => quote!(#field),
+        };
+
+        return quote! { visitor(&format_args!(#format, #args)); };
+    }
+
+    if let Some(func) = &attr.display_with {
+        let func_call = match attr.display_with_use_self {
+            true => use_function_with_self(func),
+            false => use_function_for(field, func),
+        };
+
+        return quote! { visitor(&::std::borrow::Cow::<'_, str>::from(#func_call)); };
+    }
+
+    if attr.nested {
+        let style = attr
+            .nested_style
+            .as_ref()
+            .map(|style| quote!(table.with(::tabled::Style::#style());));
+
+        return quote!({
+            let mut table = ::tabled::Table::new(#field.iter());
+            #style
+            visitor(&table);
+        });
+    }
+
+    if let Some(sep) = &attr.join {
+        return quote!({
+            let list = #field.iter().map(|value| value.to_string()).collect::<Vec<_>>();
+            visitor(&list.join(#sep));
+        });
+    }
+
+    quote! { visitor(&#field); }
+}
+
 fn use_function_for(field: &TokenStream, function: &str) -> TokenStream {
     let path: syn::Result<syn::ExprPath> = syn::parse_str(function);
     match path {
@@ -454,6 +1285,45 @@ fn values_for_enum(
     }
 }
 
+fn visit_for_enum(
+    variant_sizes: impl Iterator<Item = TokenStream>,
+    variants: &[(&Variant, TokenStream)],
+) -> TokenStream {
+    let mut stream = TokenStream::new();
+    for (i, (variant, visit)) in variants.iter().enumerate() {
+        let branch = match_variant(variant);
+        let branch = quote! {
+            Self::#branch => {
+                for _ in 0..offsets[#i] {
+                    visitor(&"");
+                }
+
+                #visit
+
+                for _ in offsets[#i + 1]..<Self as Tabled>::LENGTH {
+                    visitor(&"");
+                }
+            },
+        };
+
+        stream.append_all(branch);
+    }
+
+    quote! {
+        // the same offsets as in `fields`
+        let mut offsets: &mut [usize] = &mut [0, #(#variant_sizes,)*];
+        for i in 1 .. offsets.len() {
+            offsets[i] += offsets[i-1]
+        }
+
+        #[allow(unused_variables)]
+        match &self {
+            #stream
+            _ => {}
+        }
+    }
+}
+
 fn variant_idents(v: &Variant) -> Vec<TokenStream> {
     v.fields
         .iter()
@@ -517,6 +1387,65 @@ fn field_header_name(f: &Field, attr: &Attributes, index: usize) -> String {
     }
 }
 
+fn field_attributes(field: &Field, global_attr: &ObjectAttributes) -> Result<Attributes, Error> {
+    let mut attributes = Attributes::parse(&field.attrs)?;
+    merge_attributes(&mut attributes, global_attr);
+
+    let is_option = option_inner_type(&field.ty).is_some();
+    if attributes.display_option.is_some() && !is_option {
+        return Err(Error::new(
+            "`display_option` can be used only with a field of `Option` type",
+            field.ty.span(),
+            None,
+        ));
+    }
+
+    if attributes.group && !attributes.inline {
+        return Err(Error::new(
+            "`group` must be used together with `inline`",
+            field.span(),
+            None,
+        ));
+    }
+
+    if attributes.display_option.is_none() && is_option {
+        attributes.display_option = global_attr.display_option.clone();
+    }
+
+    Ok(attributes)
+}
+
+// A type which is inlined;
+// it's an inner type of an `Option` in case `None` is displayed as a placeholder.
+fn inline_type<'a>(field: &'a Field, attr: &Attributes) -> &'a Type {
+    match option_inner_type(&field.ty) {
+        Some(ty) if attr.display_option.is_some() => ty,
+        _ => &field.ty,
+    }
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(ty) if ty.qself.is_none() => &ty.path,
+        Type::Group(group) => return option_inner_type(&group.elem),
+        Type::Paren(paren) => return option_inner_type(&paren.elem),
+        _ => return None,
+    };
+
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn merge_attributes(attr: &mut Attributes, global_attr: &ObjectAttributes) {
     if attr.rename_all.is_none() {
         attr.rename_all = global_attr.rename_all;
@@ -1,7 +1,7 @@
 use proc_macro2::{Ident, Span};
 use syn::{
-    parenthesized, parse::Parse, punctuated::Punctuated, token, Attribute, LitBool, LitInt, LitStr,
-    Token,
+    parenthesized, parse::Parse, punctuated::Punctuated, token, Attribute, Expr, LitBool, LitInt,
+    LitStr, Token,
 };
 
 pub fn parse_attributes(
@@ -28,10 +28,24 @@ impl TabledAttr {
 #[derive(Clone)]
 pub enum TabledAttrKind {
     Skip(LitBool),
+    Transparent(LitBool),
     Inline(LitBool, Option<LitStr>),
+    Group,
+    ColumnIds,
+    FlattenMap,
     Rename(LitStr),
+    RenameKey(LitStr),
     RenameAll(LitStr),
     DisplayWith(LitStr, bool),
+    Format(LitStr, Option<Vec<Expr>>),
+    DisplayOption(LitStr),
+    Nested(Option<LitStr>),
+    Join(LitStr),
+    EnumDisplay(LitStr),
+    Align(LitStr),
+    MaxWidth(LitInt),
+    Truncate(LitStr),
+    Color(LitStr),
     Order(LitInt),
 }
 
@@ -50,8 +64,16 @@ impl Parse for TabledAttr {
 
                 match name_str.as_str() {
                     "rename" => return Ok(Self::new(name, Rename(lit))),
+                    "rename_key" => return Ok(Self::new(name, RenameKey(lit))),
                     "rename_all" => return Ok(Self::new(name, RenameAll(lit))),
                     "display_with" => return Ok(Self::new(name, DisplayWith(lit, false))),
+                    "display_option" => return Ok(Self::new(name, DisplayOption(lit))),
+                    "enum_display" => return Ok(Self::new(name, EnumDisplay(lit))),
+                    "join" => return Ok(Self::new(name, Join(lit))),
+                    "align" => return Ok(Self::new(name, Align(lit))),
+                    "truncate" => return Ok(Self::new(name, Truncate(lit))),
+                    "color" => return Ok(Self::new(name, Color(lit))),
+                    "format" => return Ok(Self::new(name, Format(lit, None))),
                     _ => {}
                 }
             }
@@ -61,6 +83,7 @@ impl Parse for TabledAttr {
 
                 match name_str.as_str() {
                     "skip" => return Ok(Self::new(name, Skip(lit))),
+                    "transparent" => return Ok(Self::new(name, Transparent(lit))),
                     "inline" => return Ok(Self::new(name, Inline(lit, None))),
                     _ => {}
                 }
@@ -69,8 +92,10 @@ impl Parse for TabledAttr {
             if input.peek(LitInt) {
                 let lit = input.parse::<LitInt>()?;
 
-                if let "order" = name_str.as_str() {
-                    return Ok(Self::new(name, Order(lit)));
+                match name_str.as_str() {
+                    "order" => return Ok(Self::new(name, Order(lit))),
+                    "max_width" => return Ok(Self::new(name, MaxWidth(lit))),
+                    _ => {}
                 }
             }
 
@@ -109,6 +134,19 @@ impl Parse for TabledAttr {
                             Inline(LitBool::new(true, Span::call_site()), Some(lit)),
                         ))
                     }
+                    "nested" => return Ok(Self::new(name, Nested(Some(lit)))),
+                    "format" => {
+                        let args = if nested.peek(Token![,]) {
+                            let _comma = nested.parse::<Token![,]>()?;
+                            Punctuated::<Expr, Token![,]>::parse_terminated(&nested)?
+                                .into_iter()
+                                .collect()
+                        } else {
+                            Vec::new()
+                        };
+
+                        return Ok(Self::new(name, Format(lit, Some(args))));
+                    }
                     _ => {}
                 }
             }
@@ -121,6 +159,16 @@ impl Parse for TabledAttr {
 
         match name_str.as_str() {
             "skip" => return Ok(Self::new(name, Skip(LitBool::new(true, Span::call_site())))),
+            "transparent" => {
+                return Ok(Self::new(
+                    name,
+                    Transparent(LitBool::new(true, Span::call_site())),
+                ))
+            }
+            "nested" => return Ok(Self::new(name, Nested(None))),
+            "group" => return Ok(Self::new(name, Group)),
+            "column_ids" => return Ok(Self::new(name, ColumnIds)),
+            "flatten_map" => return Ok(Self::new(name, FlattenMap)),
             "inline" => {
                 return Ok(Self::new(
                     name,
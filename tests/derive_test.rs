@@ -2,6 +2,12 @@
 
 use tabled::Tabled;
 
+fn visit_fields<T: Tabled>(value: &T) -> Vec<String> {
+    let mut fields = Vec::new();
+    value.visit_fields(&mut |field| fields.push(field.to_string()));
+    fields
+}
+
 // https://users.rust-lang.org/t/create-a-struct-from-macro-rules/19829
 macro_rules! test_tuple {
     (
@@ -16,6 +22,7 @@ macro_rules! test_tuple {
             $($($init_block)*)?
 
             #[derive(Tabled)]
+            #[allow(dead_code)]
             struct TestType(
                 $( $(#[$attr])* $ty, )*
             );
@@ -26,6 +33,7 @@ macro_rules! test_tuple {
             let headers: Vec<&'static str> = $headers.to_vec();
 
             assert_eq!(value.fields(), fields);
+            assert_eq!(visit_fields(&value), fields);
             assert_eq!(TestType::headers(), headers);
             assert_eq!(<TestType as Tabled>::LENGTH, headers.len());
             assert_eq!(<TestType as Tabled>::LENGTH, fields.len());
@@ -71,6 +79,7 @@ macro_rules! test_enum {
                     let variant = $init;
                     let fields: Vec<&'static str> = $expected.to_vec();
                     assert_eq!(variant.fields(), fields);
+                    assert_eq!(visit_fields(&variant), fields);
                 )*
             }
         }
@@ -108,6 +117,7 @@ macro_rules! test_struct {
             let headers: Vec<&'static str> = $headers.to_vec();
             assert_eq!(TestType::headers(), headers);
             assert_eq!(value.fields(), fields);
+            assert_eq!(visit_fields(&value), fields);
             assert_eq!(<TestType as Tabled>::LENGTH, headers.len());
             assert_eq!(<TestType as Tabled>::LENGTH, fields.len());
         }
@@ -213,6 +223,13 @@ mod tuple {
         }
     );
 
+    test_tuple!(
+        format,
+        t: { #[tabled(format = "{:05}")] u8 #[tabled(format("{}, {}", self.0, self.1))] sstr },
+        init: { 7 "v2" },
+        expected: ["0", "1"], ["00007", "7, v2"],
+    );
+
     // #[test]
     // fn order_compile_fail_when_order_is_bigger_then_count_fields() {
     //     #[derive(Tabled)]
@@ -318,6 +335,121 @@ mod enum_ {
             Frontend => ["", "+"],
     );
 
+    test_enum!(
+        inline_field_with_format,
+        t: {
+            #[tabled(inline)]
+            Measure { #[tabled(format = "{:.1}s")] time: f32 }
+            Unknown
+        },
+        headers: ["time", "Unknown"],
+        tests:
+            Measure { time: 0.25 } => ["0.2s", ""],
+            Unknown => ["", "+"],
+    );
+
+    test_enum!(
+        enum_display_name,
+        t: #[tabled(enum_display = "name", rename_all = "snake_case")] {
+            InProgress
+            #[tabled(rename = "ok")]
+            Done { code: u8 }
+            Failed(sstr)
+            #[tabled(skip)]
+            Hidden
+        },
+        headers: ["TestType"],
+        tests:
+            InProgress => ["in_progress"],
+            Done { code: 0 } => ["ok"],
+            Failed("timeout") => ["failed"],
+            Hidden => [""],
+    );
+
+    test_enum!(
+        enum_display_name_rename,
+        t: #[tabled(enum_display = "name", rename = "status")] { Active Inactive },
+        headers: ["status"],
+        tests:
+            Active => ["Active"],
+            Inactive => ["Inactive"],
+    );
+
+    test_enum!(
+        enum_display_union,
+        t: #[tabled(enum_display = "union")] {
+            Circle { x: i32, y: i32, radius: u32 }
+            Point { x: i32, y: i32 }
+            Line { #[tabled(rename = "x")] x1: i32, #[tabled(skip)] x2: i32 }
+            Polygon(#[tabled(format = "{} vertices")] usize)
+            Empty
+        },
+        headers: ["TestType", "x", "y", "radius", "0"],
+        tests:
+            Circle { x: 1, y: 2, radius: 3 } => ["Circle", "1", "2", "3", ""],
+            Point { x: 4, y: 5 } => ["Point", "4", "5", "", ""],
+            Line { x1: 6, x2: 7 } => ["Line", "6", "", "", ""],
+            Polygon(8) => ["Polygon", "", "", "", "8 vertices"],
+            Empty => ["Empty", "", "", "", ""],
+    );
+
+    test_enum!(
+        enum_display_union_display_option,
+        t: #[tabled(enum_display = "union", display_option = "-")] {
+            Set { value: Option<u8> }
+            Unset
+        },
+        headers: ["TestType", "value"],
+        tests:
+            Set { value: None } => ["Set", "-"],
+            Set { value: Some(1) } => ["Set", "1"],
+            Unset => ["Unset", ""],
+    );
+
+    test_enum!(
+        enum_display_union_hidden_variant,
+        t: #[tabled(enum_display = "union")] {
+            Set { #[tabled(display_with = "display_value")] value: u8 }
+            #[tabled(skip)]
+            Hidden { value: u8 }
+        },
+        pre: {
+            fn display_value(value: &u8) -> String { format!("<{}>", value) }
+        }
+        headers: ["TestType", "value"],
+        tests:
+            Set { value: 1 } => ["Set", "<1>"],
+            Hidden { value: 2 } => ["", ""],
+    );
+
+    test_enum!(
+        inline_unit_variant,
+        t: {
+            #[tabled(inline("status::"))]
+            Unknown
+            #[tabled(inline)]
+            Code(u16)
+        },
+        headers: ["Unknown", "0"],
+        tests:
+            Unknown => ["+", ""],
+            Code(404) => ["", "404"],
+    );
+
+    test_enum!(
+        inline_field_with_display_option,
+        t: #[tabled(display_option = "-")] {
+            #[tabled(inline)]
+            Measure { time: Option<u8>, #[tabled(display_option = "?")] unit: Option<sstr> }
+            Unknown
+        },
+        headers: ["time", "unit", "Unknown"],
+        tests:
+            Measure { time: Some(3), unit: None } => ["3", "?", ""],
+            Measure { time: None, unit: Some("s") } => ["-", "s", ""],
+            Unknown => ["", "", "+"],
+    );
+
     test_enum!(
         with_display,
         t: {
@@ -486,9 +618,244 @@ mod unit {
         struct St;
         let st = St;
 
-        assert!(st.fields().is_empty());
-        assert!(St::headers().is_empty());
-        assert_eq!(St::LENGTH, 0);
+        assert_eq!(st.fields(), vec!["+"]);
+        assert_eq!(St::headers(), vec!["St"]);
+        assert_eq!(St::LENGTH, 1);
+    }
+
+    #[test]
+    fn rename() {
+        #[derive(Tabled)]
+        #[tabled(rename = "Marker")]
+        struct St;
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "UPPERCASE")]
+        struct St2;
+
+        assert_eq!(St.fields(), vec!["+"]);
+        assert_eq!(St::headers(), vec!["Marker"]);
+        assert_eq!(St2::headers(), vec!["ST2"]);
+    }
+
+    #[test]
+    fn inline() {
+        #[derive(Tabled)]
+        struct Marker;
+
+        #[derive(Tabled)]
+        struct St {
+            id: u8,
+            #[tabled(inline)]
+            marker: Marker,
+        }
+
+        let st = St {
+            id: 0,
+            marker: Marker,
+        };
+
+        assert_eq!(st.fields(), vec!["0", "+"]);
+        assert_eq!(St::headers(), vec!["id", "Marker"]);
+        assert_eq!(St::LENGTH, 2);
+    }
+}
+
+mod transparent {
+    use super::*;
+    use tabled::Table;
+
+    #[test]
+    fn newtype() {
+        #[derive(Tabled)]
+        #[tabled(transparent)]
+        struct UserId(u64);
+
+        assert_eq!(UserId(1).fields(), vec!["1"]);
+        assert_eq!(UserId::headers(), vec!["u64"]);
+        assert_eq!(UserId::LENGTH, 1);
+    }
+
+    #[test]
+    fn delegates_to_tabled() {
+        #[derive(Tabled)]
+        struct User {
+            id: u64,
+            name: sstr,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(transparent)]
+        struct Admin {
+            user: User,
+            #[tabled(skip)]
+            #[allow(dead_code)]
+            level: u8,
+        }
+
+        let admin = Admin {
+            user: User {
+                id: 0,
+                name: "root",
+            },
+            level: 1,
+        };
+
+        assert_eq!(admin.fields(), vec!["0", "root"]);
+        assert_eq!(Admin::headers(), vec!["id", "name"]);
+        assert_eq!(Admin::LENGTH, 2);
+    }
+
+    #[test]
+    fn generic() {
+        #[derive(Tabled)]
+        #[tabled(transparent)]
+        struct Wrapper<T: Tabled>(T);
+
+        #[derive(Tabled)]
+        struct St {
+            #[tabled(inline)]
+            id: Wrapper<u8>,
+            #[tabled(inline)]
+            point: Wrapper<(u8, sstr)>,
+        }
+
+        let st = St {
+            id: Wrapper(0),
+            point: Wrapper((1, "2")),
+        };
+
+        assert_eq!(st.fields(), vec!["0", "1", "2"]);
+        assert_eq!(St::headers(), vec!["id", "u8", "&str"]);
+        assert_eq!(St::LENGTH, 3);
+    }
+
+    #[test]
+    fn inline_uses_field_name() {
+        #[derive(Tabled)]
+        #[tabled(transparent)]
+        struct UserId(u64);
+
+        #[derive(Tabled)]
+        struct Account {
+            #[tabled(inline)]
+            owner: UserId,
+            #[tabled(inline("user_"), rename = "id")]
+            user: UserId,
+            name: sstr,
+        }
+
+        let account = Account {
+            owner: UserId(1),
+            user: UserId(2),
+            name: "main",
+        };
+
+        assert_eq!(account.fields(), vec!["1", "2", "main"]);
+        assert_eq!(Account::headers(), vec!["owner", "user_id", "name"]);
+        assert_eq!(Account::LENGTH, 3);
+    }
+
+    #[test]
+    fn delegates_visit_fields() {
+        #[derive(Tabled)]
+        struct User {
+            id: u64,
+            name: sstr,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(transparent)]
+        struct Admin(User);
+
+        let admin = Admin(User {
+            id: 0,
+            name: "root",
+        });
+
+        let mut fields = Vec::new();
+        admin.visit_fields(&mut |field| fields.push(field.to_string()));
+
+        assert_eq!(fields, vec!["0", "root"]);
+    }
+
+    #[test]
+    fn delegates_header_groups() {
+        #[derive(Tabled)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        #[derive(Tabled)]
+        struct Shape {
+            name: sstr,
+            #[tabled(inline, group)]
+            start: Point,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(transparent)]
+        struct Wrapper(Shape);
+
+        assert_eq!(
+            Wrapper::header_groups(),
+            vec![("".into(), 1), ("start".into(), 2)]
+        );
+
+        let data = [Wrapper(Shape {
+            name: "dot",
+            start: Point { x: 1, y: 2 },
+        })];
+
+        assert_eq!(
+            Table::new(data).to_string(),
+            concat!(
+                "+------+---+---+\n",
+                "| name | start |\n",
+                "+      +---+---+\n",
+                "|      | x | y |\n",
+                "+------+---+---+\n",
+                "| dot  | 1 | 2 |\n",
+                "+------+---+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn delegates_dynamic_fields() {
+        use std::collections::BTreeMap;
+
+        #[derive(Tabled)]
+        struct Pod {
+            name: sstr,
+            #[tabled(flatten_map)]
+            labels: BTreeMap<String, String>,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(transparent)]
+        struct Wrapper(Pod);
+
+        let mut labels = BTreeMap::new();
+        labels.insert("app".to_owned(), "web".to_owned());
+
+        let pod = Wrapper(Pod {
+            name: "web-0",
+            labels,
+        });
+
+        assert_eq!(pod.dynamic_fields(), vec![("app".into(), "web".into())]);
+        assert_eq!(
+            Table::new([pod]).to_string(),
+            concat!(
+                "+-------+-----+\n",
+                "| name  | app |\n",
+                "+-------+-----+\n",
+                "| web-0 | web |\n",
+                "+-------+-----+",
+            )
+        );
     }
 }
 
@@ -590,6 +957,116 @@ mod structure {
         init: { f1: 0, f2: Some("v2") }
         expected: ["f1", "f2"], ["0", "some v2"]
     );
+    test_struct!(
+        format,
+        t: {
+            #[tabled(format = "{:.2}")]
+            f1: f64,
+            #[tabled(format = "<{:>4}>")]
+            f2: sstr,
+        }
+        init: { f1: 1.23456, f2: "v2" }
+        expected: ["f1", "f2"], ["1.23", "<  v2>"]
+    );
+    test_struct!(
+        format_with_args,
+        t: {
+            #[tabled(format("{} ms", self.latency))]
+            latency: u64,
+            #[tabled(format("{}/{}", self.host, self.latency), rename = "address")]
+            host: sstr,
+            #[tabled(format("{{static}}"))]
+            f3: u8,
+        }
+        init: { latency: 120, host: "localhost", f3: 0 }
+        expected: ["latency", "address", "f3"], ["120 ms", "localhost/120", "{static}"]
+    );
+    test_struct!(
+        format_with_width_and_precision_args,
+        t: {
+            #[tabled(format("{:>1$}", self.f1, self.width))]
+            f1: u8,
+            #[tabled(format("{:.*}", self.width, self.f2))]
+            f2: f64,
+            #[tabled(format("{0:.1$}|{0:<2$}", self.f2, self.width, 6))]
+            f3: u8,
+            #[tabled(skip)]
+            width: usize,
+        }
+        init: { f1: 7, f2: 1.23456, f3: 0, width: 3 }
+        expected: ["f1", "f2", "f3"], ["  7", "1.235", "1.235|1.23456"]
+    );
+    test_struct!(
+        join,
+        t: {
+            id: u8,
+            #[tabled(join = ", ")]
+            tags: Vec<sstr>,
+            #[tabled(join = "|", display_option = "-")]
+            scores: Option<[u8; 3]>,
+        }
+        init: { id: 0, tags: vec!["a", "b"], scores: Some([1, 2, 3]) }
+        expected: ["id", "tags", "scores"], ["0", "a, b", "1|2|3"]
+    );
+    test_struct!(
+        nested,
+        t: {
+            id: u8,
+            #[tabled(nested)]
+            tags: Vec<Tag>,
+            #[tabled(nested("psql"))]
+            empty: Vec<Tag>,
+        }
+        pre: {
+            #[derive(Tabled)]
+            struct Tag { name: sstr, weight: u8 }
+        }
+        init: { id: 0, tags: vec![Tag { name: "rust", weight: 1 }], empty: vec![] }
+        expected: ["id", "tags", "empty"], [
+            "0",
+            "+------+--------+\n| name | weight |\n+------+--------+\n| rust | 1      |\n+------+--------+",
+            " name | weight ",
+        ]
+    );
+    test_struct!(
+        display_option,
+        t: {
+            f1: u8,
+            #[tabled(display_option = "-")]
+            f2: Option<sstr>,
+            #[tabled(display_option = "none", format = "{:.1}")]
+            f3: Option<f32>,
+        }
+        init: { f1: 0, f2: None, f3: Some(1.25) }
+        expected: ["f1", "f2", "f3"], ["0", "-", "1.2"]
+    );
+    test_struct!(
+        display_option_container,
+        t: #[tabled(display_option = "-")] {
+            f1: Option<u8>,
+            #[tabled(display_option = "n/a")]
+            f2: Option<sstr>,
+            f3: sstr,
+        }
+        init: { f1: Some(1), f2: None, f3: "v3" }
+        expected: ["f1", "f2", "f3"], ["1", "n/a", "v3"]
+    );
+    test_struct!(
+        display_option_inline,
+        t: #[tabled(display_option = "-")] {
+            id: u8,
+            #[tabled(inline)]
+            ed: Option<Education>,
+            #[tabled(inline("next::"))]
+            next: std::option::Option<Education>,
+        }
+        pre: {
+            #[derive(Tabled)]
+            struct Education { uni: sstr, graduated: bool }
+        }
+        init: { id: 0, ed: None, next: Some(Education { uni: "BNTU", graduated: true }) }
+        expected: ["id", "uni", "graduated", "next::uni", "next::graduated"], ["0", "-", "-", "BNTU", "true"]
+    );
     test_struct!(
         display_with_self_2_self_static_method_2,
         t: {
@@ -767,6 +1244,7 @@ fn rename_all_variants() {
         ( $name:ident, $case:expr ) => {
             #[derive(Tabled)]
             #[tabled(rename_all = $case)]
+            #[allow(dead_code)]
             struct $name {
                 field: usize,
             }
@@ -848,6 +1326,7 @@ fn test_order_skip_usage() {
     }
 
     #[derive(Tabled, Default)]
+    #[allow(dead_code)]
     pub struct Example2 {
         #[tabled(skip)]
         #[allow(dead_code)]
@@ -860,3 +1339,780 @@ fn test_order_skip_usage() {
     assert_eq!(Example::headers(), vec!["details", "name"],);
     assert_eq!(Example::default().fields(), vec!["", ""]);
 }
+
+mod column_settings {
+    use tabled::{builder::Builder, Table, TableIteratorExt, Tabled};
+
+    #[test]
+    fn align_and_max_width() {
+        #[derive(Tabled)]
+        struct Request {
+            #[tabled(max_width = 8, truncate = "…")]
+            url: &'static str,
+            #[tabled(align = "right")]
+            latency: u64,
+        }
+
+        let data = [
+            Request {
+                url: "https://github.com",
+                latency: 130,
+            },
+            Request {
+                url: "http://a.b",
+                latency: 5,
+            },
+        ];
+
+        assert_eq!(
+            Table::new(&data).to_string(),
+            concat!(
+                "+----------+---------+\n",
+                "| url      | latency |\n",
+                "+----------+---------+\n",
+                "| https:/… |     130 |\n",
+                "+----------+---------+\n",
+                "| http://… |       5 |\n",
+                "+----------+---------+",
+            )
+        );
+        assert_eq!((&data).table().to_string(), Table::new(&data).to_string());
+    }
+
+    #[test]
+    fn borrowed_records() {
+        #[derive(Tabled)]
+        struct Request {
+            #[tabled(max_width = 8, truncate = "…")]
+            url: &'static str,
+            #[tabled(align = "right")]
+            latency: u64,
+        }
+
+        let url = String::from("https://github.com");
+
+        let mut builder = Builder::default();
+        builder.set_columns(["url", "latency"]);
+        builder.add_record([url.as_str(), "130"]);
+
+        let mut table = builder.build();
+        Request::column_settings(&mut table, 0);
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "+----------+---------+\n",
+                "| url      | latency |\n",
+                "+----------+---------+\n",
+                "| https:/… |     130 |\n",
+                "+----------+---------+",
+            )
+        );
+    }
+
+    #[test]
+    fn all_constructors() {
+        use std::collections::BTreeMap;
+
+        #[derive(Tabled)]
+        struct Point {
+            #[tabled(align = "right")]
+            x: i32,
+            y: i32,
+        }
+
+        #[derive(Tabled)]
+        struct Line {
+            #[tabled(max_width = 3)]
+            name: &'static str,
+            #[tabled(inline, group)]
+            start: Point,
+            #[tabled(flatten_map)]
+            labels: BTreeMap<&'static str, &'static str>,
+        }
+
+        let data = [
+            Line {
+                name: "first",
+                start: Point { x: 100, y: 2 },
+                labels: BTreeMap::from([("kind", "solid")]),
+            },
+            Line {
+                name: "second",
+                start: Point { x: 1, y: 200 },
+                labels: BTreeMap::new(),
+            },
+        ];
+
+        let expected = concat!(
+            "+-----+-----+-----+-------+\n",
+            "| nam |     start | kind  |\n",
+            "+     +-----+-----+       +\n",
+            "|     |   x | y   |       |\n",
+            "+-----+-----+-----+-------+\n",
+            "| fir | 100 | 2   | solid |\n",
+            "+-----+-----+-----+-------+\n",
+            "| sec |   1 | 200 |       |\n",
+            "+-----+-----+-----+-------+",
+        );
+
+        assert_eq!(Table::new(&data).to_string(), expected);
+        assert_eq!(data.iter().collect::<Table<_>>().to_string(), expected);
+        assert_eq!(Table::builder(&data).build().to_string(), expected);
+    }
+
+    #[test]
+    fn inline_and_order() {
+        #[derive(Tabled)]
+        struct Point {
+            #[tabled(align = "center")]
+            x: i32,
+            y: i32,
+        }
+
+        #[derive(Tabled)]
+        struct Shape {
+            #[tabled(max_width = 3)]
+            name: &'static str,
+            #[tabled(inline("p."), align = "right")]
+            p: Point,
+            #[tabled(order = 0, align = "right")]
+            id: usize,
+        }
+
+        let data = [Shape {
+            name: "triangle",
+            p: Point { x: 100, y: 2000 },
+            id: 1,
+        }];
+
+        assert_eq!(
+            Table::new(data).to_string(),
+            concat!(
+                "+----+-----+-----+------+\n",
+                "| id | nam | p.x |  p.y |\n",
+                "+----+-----+-----+------+\n",
+                "|  1 | tri | 100 | 2000 |\n",
+                "+----+-----+-----+------+",
+            )
+        );
+    }
+
+    #[test]
+    fn enum_variants() {
+        #[derive(Tabled)]
+        enum Status {
+            #[tabled(align = "center")]
+            Ok,
+            #[tabled(inline)]
+            Failed {
+                #[tabled(align = "right")]
+                code: u16,
+            },
+        }
+
+        let data = [Status::Ok, Status::Failed { code: 404 }];
+
+        assert_eq!(
+            Table::new(data).to_string(),
+            concat!(
+                "+----+------+\n",
+                "| Ok | code |\n",
+                "+----+------+\n",
+                "| +  |      |\n",
+                "+----+------+\n",
+                "|    |  404 |\n",
+                "+----+------+",
+            )
+        );
+    }
+
+    #[test]
+    fn enum_display_union() {
+        #[derive(Tabled)]
+        #[tabled(enum_display = "union")]
+        enum Shape {
+            Circle {
+                #[tabled(align = "right")]
+                radius: u32,
+            },
+            Square {
+                side: u32,
+                #[tabled(align = "right")]
+                radius: u32,
+            },
+        }
+
+        let data = [
+            Shape::Circle { radius: 5 },
+            Shape::Square {
+                side: 10,
+                radius: 0,
+            },
+        ];
+
+        assert_eq!(
+            Table::new(data).to_string(),
+            concat!(
+                "+--------+--------+------+\n",
+                "| Shape  | radius | side |\n",
+                "+--------+--------+------+\n",
+                "| Circle |      5 |      |\n",
+                "+--------+--------+------+\n",
+                "| Square |      0 | 10   |\n",
+                "+--------+--------+------+",
+            )
+        );
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn color() {
+        #[derive(Tabled)]
+        struct Alert {
+            #[tabled(color = "red")]
+            level: &'static str,
+            #[tabled(color = "bg_bright_blue")]
+            message: &'static str,
+        }
+
+        let data = [Alert {
+            level: "E",
+            message: "msg",
+        }];
+
+        assert_eq!(
+            Table::new(data).to_string(),
+            concat!(
+                "+-------+---------+\n",
+                "| \u{1b}[31mlevel\u{1b}[39m | \u{1b}[104mmessage\u{1b}[49m |\n",
+                "+-------+---------+\n",
+                "| \u{1b}[31mE\u{1b}[39m     | \u{1b}[104mmsg\u{1b}[49m     |\n",
+                "+-------+---------+",
+            )
+        );
+    }
+}
+
+mod header_groups {
+    use tabled::{Table, Tabled};
+
+    #[derive(Tabled)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn group() {
+        #[derive(Tabled)]
+        struct Shape {
+            name: &'static str,
+            #[tabled(inline, group)]
+            start: Point,
+            #[tabled(inline, group, rename = "End")]
+            end: Point,
+        }
+
+        assert_eq!(
+            Shape::header_groups(),
+            vec![("".into(), 1), ("start".into(), 2), ("End".into(), 2)]
+        );
+
+        let data = [Shape {
+            name: "line",
+            start: Point { x: 0, y: 0 },
+            end: Point { x: 10, y: 20 },
+        }];
+
+        assert_eq!(
+            Table::new(data).to_string(),
+            concat!(
+                "+------+---+---+----+----+\n",
+                "| name | start | End     |\n",
+                "+      +---+---+----+----+\n",
+                "|      | x | y | x  | y  |\n",
+                "+------+---+---+----+----+\n",
+                "| line | 0 | 0 | 10 | 20 |\n",
+                "+------+---+---+----+----+",
+            )
+        );
+    }
+
+    #[test]
+    fn group_in_tuple() {
+        #[derive(Tabled)]
+        struct Line {
+            #[tabled(inline, group)]
+            start: Point,
+        }
+
+        assert_eq!(
+            <(u8, Line, Point)>::header_groups(),
+            vec![("".into(), 1), ("start".into(), 2), ("".into(), 2)]
+        );
+        assert!(<(u8, Point)>::header_groups().is_empty());
+
+        let data = [(
+            1u8,
+            Line {
+                start: Point { x: 0, y: 0 },
+            },
+            Point { x: 5, y: 6 },
+        )];
+
+        assert_eq!(
+            Table::new(data).to_string(),
+            concat!(
+                "+----+---+---+---+---+\n",
+                "| u8 | start | x | y |\n",
+                "+    +---+---+   +   +\n",
+                "|    | x | y |   |   |\n",
+                "+----+---+---+---+---+\n",
+                "| 1  | 0 | 0 | 5 | 6 |\n",
+                "+----+---+---+---+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn group_with_order() {
+        #[derive(Tabled)]
+        struct Shape {
+            #[tabled(inline, group)]
+            point: Point,
+            #[tabled(inline)]
+            other: Point,
+            #[tabled(order = 0)]
+            id: usize,
+        }
+
+        assert_eq!(
+            Shape::header_groups(),
+            vec![("".into(), 1), ("point".into(), 2), ("".into(), 2)]
+        );
+
+        let data = [Shape {
+            point: Point { x: 1, y: 2 },
+            other: Point { x: 3, y: 4 },
+            id: 0,
+        }];
+
+        assert_eq!(
+            Table::new(data).to_string(),
+            concat!(
+                "+----+---+---+---+---+\n",
+                "| id | point | x | y |\n",
+                "+    +---+---+   +   +\n",
+                "|    | x | y |   |   |\n",
+                "+----+---+---+---+---+\n",
+                "| 0  | 1 | 2 | 3 | 4 |\n",
+                "+----+---+---+---+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn no_groups() {
+        #[derive(Tabled)]
+        struct Shape {
+            #[tabled(inline)]
+            point: Point,
+        }
+
+        assert!(Shape::header_groups().is_empty());
+    }
+}
+
+mod header_keys {
+    use tabled::{Table, Tabled};
+
+    fn lookup(key: &str) -> Option<String> {
+        match key {
+            "user.name" => Some("Nom".to_owned()),
+            "user.age" => Some("Âge".to_owned()),
+            "address.city" => Some("Ville".to_owned()),
+            "status.active" => Some("Actif".to_owned()),
+            _ => None,
+        }
+    }
+
+    #[derive(Tabled)]
+    struct Address {
+        #[tabled(rename_key = "address.city")]
+        city: &'static str,
+        zip: u32,
+    }
+
+    #[derive(Tabled)]
+    struct User {
+        #[tabled(rename = "name", rename_key = "user.name")]
+        first_name: &'static str,
+        #[tabled(rename_key = "user.age", order = 0)]
+        age: u8,
+        #[tabled(rename_key = "user.unknown")]
+        nick: &'static str,
+        #[tabled(inline("addr."))]
+        address: Address,
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(
+            User::headers(),
+            vec!["age", "name", "nick", "addr.city", "addr.zip"]
+        );
+        assert_eq!(
+            User::header_keys(),
+            vec![
+                "user.age",
+                "user.name",
+                "user.unknown",
+                "addr.address.city",
+                "addr.zip"
+            ]
+        );
+        assert_eq!(Address::header_keys(), vec!["address.city", "zip"]);
+    }
+
+    #[test]
+    fn keys_default_to_headers() {
+        #[derive(Tabled)]
+        struct Point {
+            x: i32,
+            #[tabled(rename = "Y")]
+            y: i32,
+        }
+
+        assert_eq!(Point::header_keys(), Point::headers());
+        assert_eq!(
+            <(Point, Address)>::header_keys(),
+            vec!["x", "Y", "address.city", "zip"]
+        );
+    }
+
+    #[test]
+    fn table() {
+        let data = [User {
+            first_name: "Alice",
+            age: 30,
+            nick: "al",
+            address: Address {
+                city: "Paris",
+                zip: 75001,
+            },
+        }];
+
+        assert_eq!(
+            Table::new_with_headers(&data, lookup).to_string(),
+            concat!(
+                "+-----+-------+------+-----------+----------+\n",
+                "| Âge | Nom   | nick | addr.city | addr.zip |\n",
+                "+-----+-------+------+-----------+----------+\n",
+                "| 30  | Alice | al   | Paris     | 75001    |\n",
+                "+-----+-------+------+-----------+----------+",
+            )
+        );
+        assert_eq!(
+            Table::new_with_headers(&data, |_| None).to_string(),
+            Table::new(&data).to_string()
+        );
+    }
+
+    #[test]
+    fn enum_variants() {
+        #[derive(Tabled)]
+        enum Status {
+            #[tabled(rename_key = "status.active")]
+            Active,
+            #[tabled(inline)]
+            Moved(#[tabled(inline)] Address),
+        }
+
+        assert_eq!(
+            Status::header_keys(),
+            vec!["status.active", "address.city", "zip"]
+        );
+
+        let data = [
+            Status::Active,
+            Status::Moved(Address {
+                city: "Lyon",
+                zip: 69001,
+            }),
+        ];
+        assert_eq!(
+            Table::new_with_headers(data, lookup).to_string(),
+            concat!(
+                "+-------+-------+-------+\n",
+                "| Actif | Ville | zip   |\n",
+                "+-------+-------+-------+\n",
+                "| +     |       |       |\n",
+                "+-------+-------+-------+\n",
+                "|       | Lyon  | 69001 |\n",
+                "+-------+-------+-------+",
+            )
+        );
+    }
+
+    #[test]
+    fn enum_display_union() {
+        #[derive(Tabled)]
+        #[tabled(enum_display = "union")]
+        enum Status {
+            Active {
+                #[tabled(rename_key = "user.name")]
+                name: &'static str,
+            },
+            Banned {
+                #[tabled(rename_key = "user.name")]
+                name: &'static str,
+                reason: &'static str,
+            },
+        }
+
+        assert_eq!(Status::header_keys(), vec!["Status", "user.name", "reason"]);
+
+        let data = [
+            Status::Active { name: "Anna" },
+            Status::Banned {
+                name: "Tom",
+                reason: "spam",
+            },
+        ];
+        assert_eq!(
+            Table::new_with_headers(data, lookup).to_string(),
+            concat!(
+                "+--------+------+--------+\n",
+                "| Status | Nom  | reason |\n",
+                "+--------+------+--------+\n",
+                "| Active | Anna |        |\n",
+                "+--------+------+--------+\n",
+                "| Banned | Tom  | spam   |\n",
+                "+--------+------+--------+",
+            )
+        );
+    }
+}
+
+mod column_ids {
+    use std::ops::{Bound, RangeBounds};
+
+    use tabled::{Alignment, Disable, Extract, Modify, Table, Tabled};
+
+    #[derive(Tabled)]
+    struct Address {
+        city: &'static str,
+        zip: u32,
+    }
+
+    #[derive(Tabled)]
+    #[tabled(column_ids)]
+    struct User {
+        #[tabled(skip)]
+        #[allow(dead_code)]
+        id: usize,
+        #[tabled(rename = "Name")]
+        name: &'static str,
+        #[tabled(inline)]
+        address: Address,
+        #[tabled(order = 0)]
+        email: &'static str,
+    }
+
+    fn data() -> [User; 1] {
+        [User {
+            id: 0,
+            name: "Alice",
+            address: Address {
+                city: "Paris",
+                zip: 75001,
+            },
+            email: "alice@example.com",
+        }]
+    }
+
+    #[test]
+    fn indexes() {
+        assert_eq!(usize::from(User::COLUMNS.email), 0);
+        assert_eq!(usize::from(User::COLUMNS.name), 1);
+
+        let address = User::COLUMNS.address;
+        assert_eq!(address.start_bound(), Bound::Included(&2));
+        assert_eq!(address.end_bound(), Bound::Excluded(&4));
+    }
+
+    #[test]
+    fn modify_and_disable() {
+        let table = Table::new(data())
+            .with(Modify::new(User::COLUMNS.address).with(Alignment::right()))
+            .with(Disable::column(User::COLUMNS.email))
+            .to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "+-------+-------+-------+\n",
+                "| Name  | city  |   zip |\n",
+                "+-------+-------+-------+\n",
+                "| Alice | Paris | 75001 |\n",
+                "+-------+-------+-------+",
+            )
+        );
+    }
+
+    #[test]
+    fn extract() {
+        let table = Table::new(data())
+            .with(Extract::columns(User::COLUMNS.name))
+            .to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "+-------+\n",
+                "| Name  |\n",
+                "+-------+\n",
+                "| Alice |\n",
+                "+-------+",
+            )
+        );
+    }
+}
+
+mod flatten_map {
+    use std::collections::{BTreeMap, HashMap};
+
+    use tabled::{display::ExpandedDisplay, Table, Tabled};
+
+    #[derive(Tabled)]
+    struct Pod {
+        name: &'static str,
+        #[tabled(flatten_map)]
+        labels: HashMap<String, String>,
+        status: &'static str,
+    }
+
+    fn pods() -> Vec<Pod> {
+        vec![
+            Pod {
+                name: "web",
+                labels: HashMap::from([
+                    ("tier".to_owned(), "frontend".to_owned()),
+                    ("app".to_owned(), "shop".to_owned()),
+                ]),
+                status: "Running",
+            },
+            Pod {
+                name: "db",
+                labels: HashMap::from([("tier".to_owned(), "backend".to_owned())]),
+                status: "Pending",
+            },
+            Pod {
+                name: "job",
+                labels: HashMap::new(),
+                status: "Done",
+            },
+        ]
+    }
+
+    #[test]
+    fn static_columns() {
+        assert_eq!(Pod::LENGTH, 2);
+        assert_eq!(Pod::headers(), vec!["name", "status"]);
+    }
+
+    #[test]
+    fn expanded_display() {
+        assert_eq!(
+            ExpandedDisplay::new(pods()).to_string(),
+            concat!(
+                "-[ RECORD 0 ]----\n",
+                "name   | web\n",
+                "status | Running\n",
+                "app    | shop\n",
+                "tier   | frontend\n",
+                "-[ RECORD 1 ]----\n",
+                "name   | db\n",
+                "status | Pending\n",
+                "app    | \n",
+                "tier   | backend\n",
+                "-[ RECORD 2 ]----\n",
+                "name   | job\n",
+                "status | Done\n",
+                "app    | \n",
+                "tier   | ",
+            )
+        );
+    }
+
+    #[test]
+    fn table() {
+        assert_eq!(
+            Table::new(pods()).to_string(),
+            concat!(
+                "+------+---------+------+----------+\n",
+                "| name | status  | app  | tier     |\n",
+                "+------+---------+------+----------+\n",
+                "| web  | Running | shop | frontend |\n",
+                "+------+---------+------+----------+\n",
+                "| db   | Pending |      | backend  |\n",
+                "+------+---------+------+----------+\n",
+                "| job  | Done    |      |          |\n",
+                "+------+---------+------+----------+",
+            )
+        );
+    }
+
+    #[test]
+    fn builder() {
+        assert_eq!(
+            Table::builder(pods()).build().to_string(),
+            Table::new(pods()).to_string()
+        );
+    }
+
+    #[test]
+    fn inline() {
+        #[derive(Tabled)]
+        struct Meta {
+            #[tabled(flatten_map)]
+            annotations: BTreeMap<&'static str, u32>,
+        }
+
+        #[derive(Tabled)]
+        struct Node {
+            id: u8,
+            #[tabled(inline)]
+            meta: Meta,
+        }
+
+        let data = [
+            Node {
+                id: 0,
+                meta: Meta {
+                    annotations: BTreeMap::from([("cpu", 4)]),
+                },
+            },
+            Node {
+                id: 1,
+                meta: Meta {
+                    annotations: BTreeMap::from([("mem", 16)]),
+                },
+            },
+        ];
+
+        assert_eq!(
+            Table::new(data).to_string(),
+            concat!(
+                "+----+-----+-----+\n",
+                "| id | cpu | mem |\n",
+                "+----+-----+-----+\n",
+                "| 0  | 4   |     |\n",
+                "+----+-----+-----+\n",
+                "| 1  |     | 16  |\n",
+                "+----+-----+-----+",
+            )
+        );
+    }
+}
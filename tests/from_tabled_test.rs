@@ -0,0 +1,221 @@
+#![cfg(feature = "derive")]
+
+use tabled::{from_records, FromTabled, FromTabledError, FromTabledErrorKind, Tabled};
+
+#[derive(Debug, PartialEq, Tabled, FromTabled)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Tabled, FromTabled)]
+#[tabled(rename_all = "UPPERCASE")]
+struct Shape {
+    #[tabled(rename = "title")]
+    name: String,
+    #[tabled(inline("p."))]
+    point: Point,
+    #[tabled(display_option = "-")]
+    radius: Option<u32>,
+    #[tabled(skip)]
+    hidden: bool,
+    #[tabled(order = 0)]
+    id: usize,
+}
+
+fn records<T: Tabled>(data: &[T]) -> Vec<Vec<String>> {
+    let header = T::headers().into_iter().map(|s| s.into_owned()).collect();
+    let rows = data.iter().map(|value| {
+        let fields = value.fields();
+        fields.into_iter().map(|s| s.into_owned()).collect()
+    });
+
+    std::iter::once(header).chain(rows).collect()
+}
+
+#[test]
+fn round_trip() {
+    let data = vec![
+        Shape {
+            name: "circle".to_owned(),
+            point: Point { x: 1, y: -2 },
+            radius: Some(10),
+            hidden: false,
+            id: 0,
+        },
+        Shape {
+            name: "dot".to_owned(),
+            point: Point { x: 0, y: 0 },
+            radius: None,
+            hidden: false,
+            id: 1,
+        },
+    ];
+
+    let records = records(&data);
+    assert_eq!(records[0], ["ID", "title", "p.x", "p.y", "RADIUS"]);
+
+    let parsed: Vec<Shape> = from_records(records).unwrap();
+    assert_eq!(parsed, data);
+}
+
+#[test]
+fn columns_are_looked_up_by_name() {
+    let records = [["y", "z", "x"], ["1", "2", "3"]];
+    let points: Vec<Point> = from_records(records).unwrap();
+
+    assert_eq!(points, [Point { x: 3, y: 1 }]);
+}
+
+#[test]
+fn tuple_and_transparent() {
+    #[derive(Debug, PartialEq, FromTabled)]
+    struct Pair(String, u8);
+
+    #[derive(Debug, PartialEq, FromTabled)]
+    #[tabled(transparent)]
+    struct Wrapper(Point);
+
+    let pairs: Vec<Pair> = from_records([["0", "1"], ["a", "1"]]).unwrap();
+    assert_eq!(pairs, [Pair("a".to_owned(), 1)]);
+
+    let wrappers: Vec<Wrapper> = from_records([["x", "y"], ["1", "2"]]).unwrap();
+    assert_eq!(wrappers, [Wrapper(Point { x: 1, y: 2 })]);
+}
+
+#[test]
+fn transparent_primitive() {
+    #[derive(Debug, PartialEq, Tabled, FromTabled)]
+    #[tabled(transparent)]
+    struct UserId(u64);
+
+    #[derive(Debug, PartialEq, Tabled, FromTabled)]
+    struct Account {
+        #[tabled(inline)]
+        owner: UserId,
+        name: String,
+    }
+
+    let ids: Vec<UserId> = from_records([["u64"], ["7"]]).unwrap();
+    assert_eq!(ids, [UserId(7)]);
+
+    let data = vec![Account {
+        owner: UserId(1),
+        name: "main".to_owned(),
+    }];
+
+    let records = records(&data);
+    assert_eq!(records[0], ["owner", "name"]);
+
+    let parsed: Vec<Account> = from_records(records).unwrap();
+    assert_eq!(parsed, data);
+}
+
+#[test]
+fn empty_records() {
+    let points: Vec<Point> = from_records(Vec::<Vec<String>>::new()).unwrap();
+    assert!(points.is_empty());
+}
+
+#[test]
+fn parse_error() {
+    let records = [["x", "y"], ["1", "2"], ["3", "four"]];
+    let err = from_records::<Point, _, _, _>(records).unwrap_err();
+
+    assert_eq!(err.row(), 2);
+    assert_eq!(err.column(), Some(1));
+    assert_eq!(err.header(), "y");
+    assert_eq!(
+        err.kind(),
+        &FromTabledErrorKind::Parse {
+            text: "four".to_owned(),
+            message: "invalid digit found in string".to_owned(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "row 2, column 1: failed to parse \"four\" in column \"y\": invalid digit found in string"
+    );
+}
+
+#[test]
+fn missing_column_error() {
+    let records = vec![
+        vec!["ID", "title", "p.x", "RADIUS"],
+        vec!["0", "a", "1", "-"],
+    ];
+    let err: FromTabledError = from_records::<Shape, _, _, _>(records).unwrap_err();
+
+    assert_eq!(err.row(), 1);
+    assert_eq!(err.column(), None);
+    assert_eq!(err.kind(), &FromTabledErrorKind::MissingColumn);
+    assert_eq!(err.to_string(), "row 1: column \"p.y\" is missing");
+}
+
+#[test]
+fn missing_cell_error() {
+    let records = vec![vec!["x", "y"], vec!["1"]];
+    let err = from_records::<Point, _, _, _>(records).unwrap_err();
+
+    assert_eq!(err.column(), Some(1));
+    assert_eq!(err.kind(), &FromTabledErrorKind::MissingCell);
+    assert_eq!(
+        err.to_string(),
+        "row 1, column 1: a cell of column \"y\" is missing"
+    );
+}
+
+#[test]
+fn inlined_option() {
+    #[derive(Debug, PartialEq, Tabled, FromTabled)]
+    struct Marker {
+        name: String,
+        #[tabled(inline, display_option = "-")]
+        at: Option<Point>,
+        #[tabled(inline("to."))]
+        to: Option<Point>,
+    }
+
+    let data = vec![
+        Marker {
+            name: "a".to_owned(),
+            at: Some(Point { x: 1, y: 2 }),
+            to: Some(Point { x: 3, y: 4 }),
+        },
+        Marker {
+            name: "b".to_owned(),
+            at: None,
+            to: None,
+        },
+    ];
+
+    let records = records(&data);
+    assert_eq!(records[0], ["name", "x", "y", "to.x", "to.y"]);
+    assert_eq!(records[2], ["b", "-", "-", "", ""]);
+
+    let parsed: Vec<Marker> = from_records(records).unwrap();
+    assert_eq!(parsed, data);
+}
+
+#[test]
+fn option_without_placeholder() {
+    #[derive(Debug, PartialEq, FromTabled)]
+    struct Entry {
+        id: u8,
+        value: Option<u8>,
+    }
+
+    let records = [["id", "value"], ["1", "2"], ["2", ""]];
+    let entries: Vec<Entry> = from_records(records).unwrap();
+
+    assert_eq!(
+        entries,
+        [
+            Entry {
+                id: 1,
+                value: Some(2)
+            },
+            Entry { id: 2, value: None },
+        ]
+    );
+}
@@ -2605,3 +2605,41 @@ test_table!(
     "|     ! |xxxx"
     "+-------+xxxx"
 );
+
+test_table!(
+    style_multi_char_vertical_test,
+    create_table::<2, 2>().with(Style::empty().vertical(" │ ").horizontal('─').inner_intersection("─┼─")),
+    " N  │  column 0  │  column 1 "
+    "────┼────────────┼───────────"
+    " 0  │    0-0     │    0-1    "
+    "────┼────────────┼───────────"
+    " 1  │    1-0     │    1-1    "
+);
+
+test_table!(
+    style_wide_char_frame_test,
+    create_table::<1, 1>().with(Style::ascii().left("🦀").right("🦀").top('=').bottom('=')),
+    "=================="
+    "🦀 N | column 0 🦀"
+    "🦀---+----------🦀"
+    "🦀 0 |   0-0    🦀"
+    "=================="
+);
+
+test_table!(
+    border_multi_char_test,
+    create_table::<1, 1>().with(Style::ascii()).with(Modify::new(Cell(0, 0)).with(Border::default().left("<<"))),
+    "+----+----------+"
+    "<< N | column 0 |"
+    "+----+----------+"
+    "|  0 |   0-0    |"
+    "+----+----------+"
+);
+
+test_table!(
+    style_owned_symbol_test,
+    create_table::<1, 1>().with(Style::empty().vertical(String::from(" │ ")).horizontal('─').inner_intersection("─┼─".to_owned())),
+    " N  │  column 0 "
+    "────┼───────────"
+    " 0  │    0-0    "
+);
@@ -1,4 +1,10 @@
-use std::iter::FromIterator;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    iter::FromIterator,
+    rc::Rc,
+    sync::Arc,
+};
 
 use tabled::{builder::Builder, Height, Padding, Style, Table, Width};
 
@@ -307,6 +313,96 @@ test_table!(
     "└──────────────────────────────────────────────────────────────────────────────────────────────────┘"
 );
 
+test_table!(
+    table_option,
+    Table::new([Some((1, "a")), None, Some((3, "c"))]),
+    "+-----+------+"
+    "| i32 | &str |"
+    "+-----+------+"
+    "| 1   | a    |"
+    "+-----+------+"
+    "|     |      |"
+    "+-----+------+"
+    "| 3   | c    |"
+    "+-----+------+"
+);
+
+test_table!(
+    table_result,
+    Table::new(vec![Ok((1u8, "a")), Err("not found")]),
+    "+----+------+-----------+"
+    "| u8 | &str | error     |"
+    "+----+------+-----------+"
+    "| 1  | a    |           |"
+    "+----+------+-----------+"
+    "|    |      | not found |"
+    "+----+------+-----------+"
+);
+
+test_table!(
+    table_hashmap,
+    Table::new(HashMap::from([("key", "value")])),
+    "+------+-------+"
+    "| &str | &str  |"
+    "+------+-------+"
+    "| key  | value |"
+    "+------+-------+"
+);
+
+test_table!(
+    table_btreemap,
+    Table::new(BTreeMap::from([("b", 2), ("a", 1)]).iter()),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "| a    | 1   |"
+    "+------+-----+"
+    "| b    | 2   |"
+    "+------+-----+"
+);
+
+test_table!(
+    table_from_hashmap,
+    Table::from_map(HashMap::from([("key", "value")])),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+);
+
+test_table!(
+    table_from_btreemap,
+    Table::from_map(&BTreeMap::from([("b", 2), ("a", 1)])),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+    "| a   | 1     |"
+    "+-----+-------+"
+    "| b   | 2     |"
+    "+-----+-------+"
+);
+
+test_table!(
+    table_smart_pointers,
+    Table::new([(Rc::new(1), Arc::new("a"), Cow::Borrowed("b"), Cow::<i32>::Owned(2))]),
+    "+-----+------+-----+-----+"
+    "| i32 | &str | str | i32 |"
+    "+-----+------+-----+-----+"
+    "| 1   | a    | b   | 2   |"
+    "+-----+------+-----+-----+"
+);
+
+test_table!(
+    table_tuple_12,
+    Table::new([(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)]),
+    "+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+"
+    "| i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 |"
+    "+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+"
+    "| 1   | 2   | 3   | 4   | 5   | 6   | 7   | 8   | 9   | 10  | 11  | 12  |"
+    "+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+"
+);
+
 #[cfg(feature = "derive")]
 mod derived {
     use super::*;
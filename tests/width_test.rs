@@ -2631,3 +2631,20 @@ mod derived {
         );
     }
 }
+
+#[test]
+fn truncate_with_multi_char_vertical() {
+    let table = create_table::<1, 2>()
+        .with(Style::empty().vertical(" | "))
+        .with(Width::truncate(20))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "   |  colu  |  colu "
+            "   |  0-0   |  0-1  "
+        )
+    );
+    assert_eq!(string_width_multiline(&table), 20);
+}